@@ -0,0 +1,75 @@
+use std::{
+    env,
+    fs::{read_dir, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+// Declares a module for every `src/aocYY/dayNN.rs` file and gathers their
+// registrations into the `PUZZLES` table, so that adding a day only takes
+// adding its file
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=src");
+
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+
+    let mut years = numbered_entries(&src, "aoc", "")?
+        .into_iter()
+        .filter(|(_, path)| path.is_dir())
+        .map(|(year, path)| Ok((year, numbered_entries(&path, "day", ".rs")?)))
+        .collect::<io::Result<Vec<_>>>()?;
+    years.sort();
+
+    let mut modules = String::new();
+    let mut registrations = String::new();
+
+    for (year, days) in years.iter_mut() {
+        days.sort();
+
+        modules.push_str(&format!("mod aoc{year} {{\n"));
+
+        for (day, path) in days.iter() {
+            modules.push_str(&format!(
+                "    #[path = {:?}]\n    pub mod day{day};\n",
+                path.display().to_string()
+            ));
+            registrations.push_str(&format!("    aoc{year}::day{day}::REGISTRATION,\n"));
+        }
+
+        modules.push_str("}\n\n");
+    }
+
+    let generated = format!(
+        "{modules}const PUZZLES: &[crate::registry::Registration] = &[\n{registrations}];\n"
+    );
+
+    write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("puzzles.rs"),
+        generated,
+    )
+}
+
+// Entries named `<prefix><two digits><suffix>`, along with their two digits
+fn numbered_entries(
+    directory: &Path,
+    prefix: &str,
+    suffix: &str,
+) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut entries = Vec::new();
+
+    for entry in read_dir(directory)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        let number = name
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .filter(|number| number.len() == 2 && number.chars().all(|c| c.is_ascii_digit()));
+
+        if let Some(number) = number {
+            entries.push((String::from(number), entry.path()));
+        }
+    }
+
+    Ok(entries)
+}
@@ -1,34 +1,68 @@
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
+#[derive(Clone)]
 pub struct Day01 {
     elves: Vec<Vec<u64>>,
 }
 
+register_puzzle!(Day01, year = 2022, day = 1, title = "Calorie Counting");
+
+examples![
+    calories {
+        input: "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+",
+        part1: 24000,
+        part2: 45000,
+    },
+];
+
 impl Puzzle for Day01 {
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day01 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day01 {
             elves: input
                 .split("\n\n")
                 .map(|block| {
                     block
                         .lines()
-                        .map(|line| line.parse::<u64>().ok())
-                        .collect::<Option<Vec<_>>>()
+                        .map(|line| {
+                            line.parse::<u64>()
+                                .context_at(input, line, "Expected a number of calories")
+                        })
+                        .collect::<PuzzleResult<Vec<_>>>()
                 })
-                .collect::<Option<Vec<_>>>()?,
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<Self::Output> {
-        self.elves.into_iter().map(|elf| elf.into_iter().sum()).max()
+    fn part1(self) -> PuzzleResult<Self::Output1> {
+        self.elves
+            .into_iter()
+            .map(|elf| elf.into_iter().sum())
+            .max()
+            .context("There are no elves")
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let mut calories_per_elf = self.elves.into_iter().map(|elf| elf.into_iter().sum()).collect::<Vec<u64>>();
         calories_per_elf.sort();
 
-        Some(calories_per_elf.into_iter().rev().take(3).sum())
+        Ok(calories_per_elf.into_iter().rev().take(3).sum())
     }
 }
@@ -1,33 +1,60 @@
 use std::collections::HashMap;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
+#[derive(Clone)]
 pub struct Day01 {
     left: Vec<i64>,
     right: Vec<i64>,
 }
 
+register_puzzle!(Day01, year = 2024, day = 1, title = "Historian Hysteria");
+
+examples![
+    lists {
+        input: "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+",
+        part1: 11,
+        part2: 31,
+    },
+];
+
 impl Puzzle for Day01 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut left = Vec::new();
         let mut right = Vec::new();
 
         for line in input.lines() {
             let mut whitespace = line.split_whitespace();
-            left.push(whitespace.next()?.parse::<i64>().ok()?);
-            right.push(whitespace.next()?.parse::<i64>().ok()?);
+
+            for list in [&mut left, &mut right] {
+                let word = whitespace
+                    .next()
+                    .context_at(input, line, "Expected two location IDs")?;
+                list.push(
+                    word.parse::<i64>()
+                        .context_at(input, word, "Not a location ID")?,
+                );
+            }
         }
 
-        Some(Day01 { left, right })
+        Ok(Day01 { left, right })
     }
 
-    fn part1(mut self) -> Option<i64> {
+    fn part1(mut self) -> PuzzleResult<i64> {
         self.left.sort();
         self.right.sort();
 
-        Some(
+        Ok(
             self.left
                 .into_iter()
                 .zip(self.right)
@@ -36,14 +63,14 @@ impl Puzzle for Day01 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let mut right_frequency = HashMap::new();
         for number in self.right.into_iter() {
             let count = right_frequency.entry(number).or_insert(0);
             *count += 1;
         }
 
-        Some(
+        Ok(
             self.left
                 .into_iter()
                 .map(|number| number * right_frequency.get(&number).cloned().unwrap_or(0))
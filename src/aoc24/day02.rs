@@ -1,27 +1,50 @@
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
+#[derive(Clone)]
 pub struct Day02 {
     reports: Vec<Vec<i64>>,
 }
 
+register_puzzle!(Day02, year = 2024, day = 2, title = "Red-Nosed Reports");
+
+examples![
+    reports {
+        input: "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+",
+        part1: 2,
+        part2: 4,
+    },
+];
+
 impl Puzzle for Day02 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day02 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day02 {
             reports: input
                 .lines()
                 .map(|line| {
                     line.split_whitespace()
-                        .map(|number| number.parse::<i64>().ok())
-                        .collect::<Option<Vec<_>>>()
+                        .map(|number| {
+                            number
+                                .parse::<i64>()
+                                .context_at(input, number, "Not a level")
+                        })
+                        .collect::<PuzzleResult<Vec<_>>>()
                 })
-                .collect::<Option<Vec<_>>>()?,
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<i64> {
-        Some(
+    fn part1(self) -> PuzzleResult<i64> {
+        Ok(
             self.reports
                 .into_iter()
                 .filter(|report| is_report_safe(report))
@@ -29,8 +52,8 @@ impl Puzzle for Day02 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
-        Some(
+    fn part2(self) -> PuzzleResult<i64> {
+        Ok(
             self.reports
                 .into_iter()
                 .filter(|report| is_report_loosely_safe(report))
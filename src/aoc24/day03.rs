@@ -1,24 +1,39 @@
 use regex::Regex;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Puzzle, PuzzleResult};
 
+#[derive(Clone)]
 pub struct Day03 {
     input: String,
 }
 
+register_puzzle!(Day03, year = 2024, day = 3, title = "Mull It Over");
+
+examples![
+    multiplications {
+        input: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n",
+        part1: 161,
+    },
+    conditionals {
+        input: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+        part2: 48,
+    },
+];
+
 impl Puzzle for Day03 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day03 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day03 {
             input: String::from(input),
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
 
-        Some(
+        Ok(
             re.captures_iter(&self.input)
                 .map(|capture| {
                     capture[1].parse::<i64>().unwrap() * capture[2].parse::<i64>().unwrap()
@@ -27,7 +42,7 @@ impl Puzzle for Day03 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
 
         let mut enabled = true;
@@ -46,6 +61,6 @@ impl Puzzle for Day03 {
             }
         }
 
-        Some(total)
+        Ok(total)
     }
 }
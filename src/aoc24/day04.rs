@@ -1,94 +1,89 @@
-use crate::puzzle::Puzzle;
+use crate::{
+    position::{Compass, Grid, Position},
+    puzzle::{Puzzle, PuzzleResult},
+};
 
-fn check_word_against_option<const N: usize>(
-    input: &[Vec<u8>],
-    option: [(usize, usize); N],
-    possible_words: &[[u8; N]],
-) -> bool {
-    possible_words.iter().any(|word| {
-        word.iter().zip(option).all(|(c, (i, j))| {
-            input
-                .get(i)
-                .and_then(|line| line.get(j))
-                .map(|k| k == c)
-                .unwrap_or(false)
-        })
-    })
-}
-
-fn check_xmas(input: &[Vec<u8>], i: usize, j: usize) -> i64 {
-    let horizontal = [(i, j), (i + 1, j), (i + 2, j), (i + 3, j)];
-    let vertical = [(i, j), (i, j + 1), (i, j + 2), (i, j + 3)];
-    let south_east_diagonal = [(i, j), (i + 1, j + 1), (i + 2, j + 2), (i + 3, j + 3)];
-    let north_east_diagonal = [(i, j + 3), (i + 1, j + 2), (i + 2, j + 1), (i + 3, j)];
+// Whether `word` can be read from `start` onwards, going in `direction`
+fn reads(grid: &Grid<char>, start: Position, direction: Compass, word: &str) -> bool {
+    let mut position = start;
 
-    let options = [
-        horizontal,
-        vertical,
-        south_east_diagonal,
-        north_east_diagonal,
-    ];
+    word.chars().all(|letter| {
+        let found = grid.get(position) == Some(&letter);
+        position += direction.delta();
 
-    let words = [*b"XMAS", *b"SAMX"];
-
-    options
-        .into_iter()
-        .filter(|&option| check_word_against_option(input, option, &words))
-        .count() as i64
+        found
+    })
 }
 
-fn check_mas(input: &[Vec<u8>], i: usize, j: usize) -> bool {
-    let south_east_diagonal = [(i, j), (i + 1, j + 1), (i + 2, j + 2)];
-    let north_east_diagonal = [(i, j + 2), (i + 1, j + 1), (i + 2, j)];
-
-    let options = [south_east_diagonal, north_east_diagonal];
-
-    let words = [*b"MAS", *b"SAM"];
-
-    options
+// Whether "MAS" can be read across `center`, in `direction` or backwards
+fn mas_across(grid: &Grid<char>, center: Position, direction: Compass) -> bool {
+    [direction, direction.reverse()]
         .into_iter()
-        .all(|option| check_word_against_option(input, option, &words))
+        .any(|direction| reads(grid, center - direction.delta(), direction, "MAS"))
 }
 
+#[derive(Clone)]
 pub struct Day04 {
-    input: Vec<Vec<u8>>,
+    grid: Grid<char>,
 }
 
+register_puzzle!(Day04, year = 2024, day = 4, title = "Ceres Search");
+
+examples![
+    word_search {
+        input: "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+",
+        part1: 18,
+        part2: 9,
+    },
+];
+
 impl Puzzle for Day04 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day04 {
-            input: input
-                .lines()
-                .map(|line| Vec::from(line.as_bytes()))
-                .collect::<Vec<_>>(),
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day04 {
+            grid: Grid::from_map(input, |_, c| Ok(c))?,
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let mut count = 0;
 
-        for (j, line) in self.input.iter().enumerate() {
-            for (i, _) in line.iter().enumerate() {
-                count += check_xmas(&self.input, i, j)
+        for start in self.grid.positions().filter(|&start| self.grid[start] == 'X') {
+            for (next, direction) in start.neighbors8().into_iter().zip(Compass::ALL) {
+                if reads(&self.grid, next, direction, "MAS") {
+                    count += 1
+                }
             }
         }
 
-        Some(count)
+        Ok(count)
     }
 
-    fn part2(self) -> Option<i64> {
+    // The two diagonals of an X are a quarter turn apart
+    fn part2(self) -> PuzzleResult<i64> {
         let mut count = 0;
 
-        for (j, line) in self.input.iter().enumerate() {
-            for (i, _) in line.iter().enumerate() {
-                if check_mas(&self.input, i, j) {
-                    count += 1
-                }
+        for center in self.grid.positions() {
+            if mas_across(&self.grid, center, Compass::NorthEast)
+                && mas_across(&self.grid, center, Compass::NorthEast.rotate(2))
+            {
+                count += 1
             }
         }
 
-        Some(count)
+        Ok(count)
     }
 }
@@ -2,9 +2,9 @@ use std::collections::{HashMap, HashSet};
 
 use regex::Regex;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Rule {
     before: i64,
     after: i64,
@@ -70,47 +70,101 @@ impl RulesGraph {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Day05 {
     rules: Vec<Rule>,
     pages: Vec<Vec<i64>>,
 }
 
+register_puzzle!(Day05, year = 2024, day = 5, title = "Print Queue");
+
+examples![
+    print_queue {
+        input: "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        part1: 143,
+        part2: 123,
+    },
+];
+
 impl Puzzle for Day05 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        let (rules_input, pages_input) = input.split_once("\n\n")?;
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        let (rules_input, pages_input) = input
+            .split_once("\n\n")
+            .context("Expected the rules and the pages to be separated by an empty line")?;
         let rule_regex = Regex::new(r"(\d+)\|(\d+)").unwrap();
 
         let rules = rules_input
             .lines()
             .map(|line| {
-                rule_regex.captures(line).and_then(|capture| {
-                    Some(Rule {
-                        before: capture[1].parse::<i64>().ok()?,
-                        after: capture[2].parse::<i64>().ok()?,
-                    })
+                let capture = rule_regex
+                    .captures(line)
+                    .context_at(input, line, "Expected a rule of the form X|Y")?;
+                let page_number = |i: usize| {
+                    let number = capture.get(i).unwrap().as_str();
+
+                    number
+                        .parse::<i64>()
+                        .context_at(input, number, "Not a page number")
+                };
+
+                Ok(Rule {
+                    before: page_number(1)?,
+                    after: page_number(2)?,
                 })
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
         let pages = pages_input
             .lines()
             .map(|line| {
                 line.split(',')
-                    .map(|page| page.parse::<i64>().ok())
-                    .collect::<Option<Vec<_>>>()
+                    .map(|page| {
+                        page.parse::<i64>()
+                            .context_at(input, page, "Not a page number")
+                    })
+                    .collect::<PuzzleResult<Vec<_>>>()
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
-        Some(Day05 { rules, pages })
+        Ok(Day05 { rules, pages })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let graph = RulesGraph::new(&self.rules);
 
-        Some(
+        Ok(
             self.pages
                 .into_iter()
                 .filter(|page| graph.correct_page(page))
@@ -119,10 +173,10 @@ impl Puzzle for Day05 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let graph = RulesGraph::new(&self.rules);
 
-        Some(
+        Ok(
             self.pages
                 .into_iter()
                 .filter(|page| !graph.correct_page(page))
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{position::{Direction, Position}, puzzle::Puzzle};
+use crate::{
+    position::{Bounds, Direction, Position},
+    puzzle::{Context as _, Puzzle, PuzzleError, PuzzleResult},
+};
 
 
 #[derive(Clone, Copy)]
@@ -10,15 +13,6 @@ struct Guard {
 }
 
 impl Guard {
-    fn inside(self, south_east_corner: Position) -> bool {
-        let position = self.position;
-
-        0 <= position.x
-            && position.x <= south_east_corner.x
-            && 0 <= position.y
-            && position.y <= south_east_corner.y
-    }
-
     fn turn(self) -> Self {
         Guard {
             direction: self.direction.next_clockwise(),
@@ -70,13 +64,13 @@ enum Status {
 
 fn run_guard(
     mut guard: Guard,
-    grid_south_east_corner: Position,
+    bounds: Bounds,
     obstacles: &HashSet<Position>,
 ) -> (Status, HashMap<Position, Direction>) {
     let mut visited_positions = HashMap::new();
 
     loop {
-        if !guard.inside(grid_south_east_corner) {
+        if !bounds.contains(guard.position) {
             break (Status::Leaving, visited_positions);
         }
 
@@ -90,26 +84,57 @@ fn run_guard(
     }
 }
 
+#[derive(Clone)]
 pub struct Day06 {
     initial_guard: Guard,
     obstacles: HashSet<Position>,
-    grid_south_east_corner: Position,
+    bounds: Bounds,
 }
 
+register_puzzle!(Day06, year = 2024, day = 6, title = "Guard Gallivant");
+
+examples![
+    lab {
+        input: "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        part1: 41,
+        // One of the obstacles is on the last row
+        part2: 6,
+    },
+    // The only obstacle making a loop is on the last column
+    last_column {
+        input: "\
+.#...
+.....
+.....
+#^...
+...#.
+",
+        part1: 6,
+        part2: 1,
+    },
+];
+
 impl Puzzle for Day06 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut guard = None;
         let mut obstacles = HashSet::new();
-        let mut grid_south_east_corner = Position { x: 0, y: 0 };
 
         for (y, line) in input.lines().enumerate() {
-            grid_south_east_corner.y = grid_south_east_corner.y.max(y as i64);
-
             for (x, c) in line.chars().enumerate() {
-                grid_south_east_corner.x = grid_south_east_corner.x.max(x as i64);
-
                 let position = Position {
                     x: x as i64,
                     y: y as i64,
@@ -126,59 +151,55 @@ impl Puzzle for Day06 {
                                 direction: Direction::Up,
                             })
                         }
-                        Some(_) => return None,
+                        Some(_) => {
+                            return Err(PuzzleError::at(position.into(), "There is a second guard"))
+                        }
                     },
                     '.' => (),
-                    _ => return None,
+                    _ => {
+                        return Err(PuzzleError::at(
+                            position.into(),
+                            format!("Unexpected character {c}"),
+                        ))
+                    }
                 };
             }
         }
 
-        Some(Day06 {
-            initial_guard: guard?,
+        Ok(Day06 {
+            initial_guard: guard.context("There is no guard on the map")?,
             obstacles,
-            grid_south_east_corner,
+            bounds: Bounds::of_map(input),
         })
     }
 
-    fn part1(self) -> Option<i64> {
-        let (_final_status, visited_positions) = run_guard(
-            self.initial_guard,
-            self.grid_south_east_corner,
-            &self.obstacles,
-        );
+    fn part1(self) -> PuzzleResult<i64> {
+        let (_final_status, visited_positions) =
+            run_guard(self.initial_guard, self.bounds, &self.obstacles);
 
-        Some(visited_positions.len() as i64)
+        Ok(visited_positions.len() as i64)
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let mut added_obstacles = 0;
 
-        for x in 0..self.grid_south_east_corner.x {
-            for y in 0..self.grid_south_east_corner.y {
-                let new_obstacle_position = Position { x, y };
-
-                if !self.obstacles.contains(&new_obstacle_position)
-                    && new_obstacle_position != self.initial_guard.position
-                {
-                    let mut new_obstacles = self.obstacles.clone();
-                    new_obstacles.insert(new_obstacle_position);
-                    let (status, _) = run_guard(
-                        self.initial_guard,
-                        self.grid_south_east_corner,
-                        &new_obstacles,
-                    );
-
-                    match status {
-                        Status::InALoop => {
-                            added_obstacles += 1;
-                        }
-                        Status::Leaving => (),
-                    };
-                }
+        for new_obstacle_position in self.bounds.positions() {
+            if !self.obstacles.contains(&new_obstacle_position)
+                && new_obstacle_position != self.initial_guard.position
+            {
+                let mut new_obstacles = self.obstacles.clone();
+                new_obstacles.insert(new_obstacle_position);
+                let (status, _) = run_guard(self.initial_guard, self.bounds, &new_obstacles);
+
+                match status {
+                    Status::InALoop => {
+                        added_obstacles += 1;
+                    }
+                    Status::Leaving => (),
+                };
             }
         }
 
-        Some(added_obstacles)
+        Ok(added_obstacles)
     }
 }
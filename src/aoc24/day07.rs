@@ -1,4 +1,4 @@
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
 fn concatenate(hd: i64, tl: i64) -> i64 {
     hd * 10_i64.pow(1 + tl.ilog10()) + tl
@@ -30,6 +30,7 @@ fn handle_equation(
     }
 }
 
+#[derive(Clone)]
 struct Equation {
     total: i64,
     operands: Vec<i64>,
@@ -44,32 +45,58 @@ impl Equation {
     }
 }
 
+#[derive(Clone)]
 pub struct Day07 {
     equations: Vec<Equation>,
 }
 
+register_puzzle!(Day07, year = 2024, day = 7, title = "Bridge Repair");
+
+examples![
+    calibrations {
+        input: "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+",
+        part1: 3749,
+        part2: 11387,
+    },
+];
+
 impl Puzzle for Day07 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let equations = input
             .lines()
             .map(|line| {
-                let (total, operands) = line.split_once(':')?;
-                let total = total.parse::<i64>().ok()?;
+                let (total, operands) = line
+                    .split_once(':')
+                    .context_at(input, line, "Expected an equation of the form total: operands")?;
+                let total = total
+                    .parse::<i64>()
+                    .context_at(input, total, "Not a total")?;
                 let operands = operands
                     .split_whitespace()
-                    .map(|word| word.parse::<i64>().ok())
-                    .collect::<Option<Vec<_>>>()?;
-                Some(Equation { total, operands })
+                    .map(|word| word.parse::<i64>().context_at(input, word, "Not an operand"))
+                    .collect::<PuzzleResult<Vec<_>>>()?;
+                Ok(Equation { total, operands })
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
-        Some(Day07 { equations })
+        Ok(Day07 { equations })
     }
 
-    fn part1(self) -> Option<i64> {
-        Some(
+    fn part1(self) -> PuzzleResult<i64> {
+        Ok(
             self.equations
                 .into_iter()
                 .filter(|equation| equation.possible(false))
@@ -78,8 +105,8 @@ impl Puzzle for Day07 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
-        Some(
+    fn part2(self) -> PuzzleResult<i64> {
+        Ok(
             self.equations
                 .into_iter()
                 .filter(|equation| equation.possible(true))
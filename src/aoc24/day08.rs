@@ -2,16 +2,13 @@ use std::collections::{HashMap, HashSet};
 
 use gcd::Gcd as _;
 
-use crate::{position::Position, puzzle::Puzzle};
-
-impl Position {
-    fn in_bounds(self, south_east_corner: Position) -> bool {
-        0 <= self.x && self.x <= south_east_corner.x && 0 <= self.y && self.y <= south_east_corner.y
-    }
-}
+use crate::{
+    position::{Bounds, Position},
+    puzzle::{Puzzle, PuzzleResult},
+};
 
 fn per_station_antinodes(
-    south_east_corner: Position,
+    bounds: Bounds,
     antinodes: &mut HashSet<Position>,
     antennas: &[Position],
 ) {
@@ -22,7 +19,7 @@ fn per_station_antinodes(
         {
             let antinode_position = *second_station + (*second_station - *first_station);
 
-            if antinode_position.in_bounds(south_east_corner) {
+            if bounds.contains(antinode_position) {
                 let _already_seen = antinodes.insert(antinode_position);
             }
         }
@@ -30,7 +27,7 @@ fn per_station_antinodes(
 }
 
 fn pairwise_line_antinodes(
-    south_east_corner: Position,
+    bounds: Bounds,
     antinodes: &mut HashSet<Position>,
     first_station: Position,
     second_station: Position,
@@ -48,15 +45,15 @@ fn pairwise_line_antinodes(
 
     let mut next_point = first_station + unit_diff_vector;
 
-    while next_point.in_bounds(south_east_corner) {
+    while bounds.contains(next_point) {
         let _already_visited = antinodes.insert(next_point);
 
-        next_point = next_point + unit_diff_vector;
+        next_point += unit_diff_vector;
     }
 }
 
 fn in_line_antinodes(
-    south_east_corner: Position,
+    bounds: Bounds,
     antinodes: &mut HashSet<Position>,
     antennas: &[Position],
 ) {
@@ -65,35 +62,52 @@ fn in_line_antinodes(
             .iter()
             .filter(|other_station| &first_station != other_station)
         {
-            pairwise_line_antinodes(
-                south_east_corner,
-                antinodes,
-                *first_station,
-                *second_station,
-            );
+            pairwise_line_antinodes(bounds, antinodes, *first_station, *second_station);
         }
     }
 }
 
+#[derive(Clone)]
 pub struct Day08 {
     antennas: HashMap<char, Vec<Position>>,
-    south_east_corner: Position,
+    bounds: Bounds,
 }
 
+register_puzzle!(Day08, year = 2024, day = 8, title = "Resonant Collinearity");
+
+examples![
+    antennas {
+        input: "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+",
+        part1: 14,
+        part2: 34,
+    },
+];
+
 impl Puzzle for Day08 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        let mut south_east_corner = Position { x: 0, y: 0 };
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut antennas = HashMap::new();
 
         for (y, line) in input.lines().enumerate() {
             let y = y as i64;
-            south_east_corner.y = south_east_corner.y.max(y);
 
             for (x, c) in line.chars().enumerate() {
                 let x = x as i64;
-                south_east_corner.x = south_east_corner.x.max(x);
 
                 match c {
                     '.' => (),
@@ -105,29 +119,29 @@ impl Puzzle for Day08 {
             }
         }
 
-        Some(Day08 {
+        Ok(Day08 {
             antennas,
-            south_east_corner,
+            bounds: Bounds::of_map(input),
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let mut antinodes = HashSet::new();
 
         for (_, antennas) in self.antennas.iter() {
-            per_station_antinodes(self.south_east_corner, &mut antinodes, antennas);
+            per_station_antinodes(self.bounds, &mut antinodes, antennas);
         }
 
-        Some(antinodes.len() as i64)
+        Ok(antinodes.len() as i64)
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let mut antinodes = HashSet::new();
 
         for (_, antennas) in self.antennas.iter() {
-            in_line_antinodes(self.south_east_corner, &mut antinodes, antennas);
+            in_line_antinodes(self.bounds, &mut antinodes, antennas);
         }
 
-        Some(antinodes.len() as i64)
+        Ok(antinodes.len() as i64)
     }
 }
@@ -1,4 +1,4 @@
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Puzzle, PuzzleResult};
 
 #[derive(Debug, Clone, Copy)]
 struct Occupied {
@@ -146,15 +146,27 @@ fn flatten_blocks(blocks: Vec<Block>) -> Vec<Block> {
         .collect()
 }
 
+#[derive(Clone)]
 pub struct Day09 {
     disk_map: Vec<usize>,
 }
 
+register_puzzle!(Day09, year = 2024, day = 9, title = "Disk Fragmenter");
+
+examples![
+    disk_map {
+        input: "2333133121414131402\n",
+        part1: 1928,
+        part2: 2858,
+    },
+];
+
 impl Puzzle for Day09 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day09 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day09 {
             disk_map: input
                 .chars()
                 .filter_map(|c| Some(c.to_digit(10)? as usize))
@@ -162,16 +174,16 @@ impl Puzzle for Day09 {
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let blocks = to_block(&self.disk_map);
         let blocks = flatten_blocks(blocks);
         let defragmented_blocks = defragment_by_block(&blocks);
-        Some(checksum(&defragmented_blocks))
+        Ok(checksum(&defragmented_blocks))
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let blocks = to_block(&self.disk_map);
         let defragmented_blocks = defragment_by_block(&blocks);
-        Some(checksum(&defragmented_blocks))
+        Ok(checksum(&defragmented_blocks))
     }
 }
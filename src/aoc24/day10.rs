@@ -1,31 +1,28 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use crate::{position::Position, puzzle::Puzzle};
+use crate::{
+    position::{Grid, Position},
+    puzzle::{Puzzle, PuzzleError, PuzzleResult},
+};
 
+#[derive(Clone)]
 pub struct Day10 {
-    map: HashMap<Position, i64>,
+    map: Grid<i64>,
 }
 
 fn reachable_summits(
-    map: &HashMap<Position, i64>,
+    map: &Grid<i64>,
     current_position: Position,
     current_height: i64,
 ) -> HashSet<Position> {
     if current_height >= 9 {
         HashSet::from([current_position])
     } else {
-        let neighbors = current_position.neighbors();
         let next_height_target = current_height + 1;
 
-        neighbors
-            .into_iter()
-            .filter_map(|next_position| {
-                map.get(&next_position)
-                    .cloned()
-                    .filter(|&next_height| next_height == next_height_target)
-                    .map(|next_height| (next_position, next_height))
-            })
-            .flat_map(|(next_position, next_height)| {
+        map.neighbors(current_position)
+            .filter(|&(_, &next_height)| next_height == next_height_target)
+            .flat_map(|(next_position, &next_height)| {
                 reachable_summits(map, next_position, next_height).into_iter()
             })
             .collect()
@@ -33,56 +30,62 @@ fn reachable_summits(
 }
 
 fn count_all_paths(
-    map: &HashMap<Position, i64>,
+    map: &Grid<i64>,
     current_position: Position,
     current_height: i64,
 ) -> i64 {
     if current_height >= 9 {
         1
     } else {
-        let neighbors = current_position.neighbors();
         let next_height_target = current_height + 1;
 
-        neighbors
-            .into_iter()
-            .filter_map(|next_position| {
-                map.get(&next_position)
-                    .cloned()
-                    .filter(|&next_height| next_height == next_height_target)
-                    .map(|next_height| (next_position, next_height))
-            })
-            .map(|(next_position, next_height)| count_all_paths(map, next_position, next_height))
+        map.neighbors(current_position)
+            .filter(|&(_, &next_height)| next_height == next_height_target)
+            .map(|(next_position, &next_height)| count_all_paths(map, next_position, next_height))
             .sum()
     }
 }
 
-impl Puzzle for Day10 {
-    type Output = i64;
-    
-    fn parse(input: &str) -> Option<Self> {
-        let mut map = HashMap::new();
+register_puzzle!(Day10, year = 2024, day = 10, title = "Hoof It");
 
-        for (y, line) in input.lines().enumerate() {
-            for (x, height) in line.chars().enumerate() {
-                let height = height.to_digit(10)?.into();
+examples![
+    topographic_map {
+        input: "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+",
+        part1: 36,
+        part2: 81,
+    },
+];
 
-                let _ = map.insert(
-                    Position {
-                        x: x as i64,
-                        y: y as i64,
-                    },
-                    height,
-                );
-            }
-        }
+impl Puzzle for Day10 {
+    type Output1 = i64;
+    type Output2 = i64;
+    
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        let map = Grid::from_map(input, |position, height| {
+            height
+                .to_digit(10)
+                .map(i64::from)
+                .ok_or_else(|| {
+                    PuzzleError::at(position.into(), format!("{height} is not a height"))
+                })
+        })?;
 
-        Some(Day10 { map })
+        Ok(Day10 { map })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let trailheads =
             self.map.iter().filter_map(
-                |(&position, &height)| {
+                |(position, &height)| {
                     if height == 0 {
                         Some(position)
                     } else {
@@ -91,17 +94,17 @@ impl Puzzle for Day10 {
                 },
             );
 
-        Some(
+        Ok(
             trailheads
                 .map(|starting_point| reachable_summits(&self.map, starting_point, 0).len() as i64)
                 .sum(),
         )
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let trailheads =
             self.map.iter().filter_map(
-                |(&position, &height)| {
+                |(position, &height)| {
                     if height == 0 {
                         Some(position)
                     } else {
@@ -110,7 +113,7 @@ impl Puzzle for Day10 {
                 },
             );
 
-        Some(
+        Ok(
             trailheads
                 .map(|starting_point| count_all_paths(&self.map, starting_point, 0))
                 .sum(),
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
 fn step_stone(stone: i64) -> (i64, Option<i64>) {
     if stone == 0 {
@@ -58,27 +58,38 @@ fn count_stones_after_steps(stones: Vec<i64>, step_count: u32) -> i64 {
     count_all_stones(stones)
 }
 
+#[derive(Clone)]
 pub struct Day11 {
     stones: Vec<i64>,
 }
 
+register_puzzle!(Day11, year = 2024, day = 11, title = "Plutonian Pebbles");
+
+examples![
+    stones {
+        input: "125 17\n",
+        part1: 55312,
+    },
+];
+
 impl Puzzle for Day11 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day11 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day11 {
             stones: input
                 .split_whitespace()
-                .map(|stone| stone.parse::<i64>().ok())
-                .collect::<Option<Vec<_>>>()?,
+                .map(|stone| stone.parse::<i64>().context_at(input, stone, "Not a stone"))
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<i64> {
-        Some(count_stones_after_steps(self.stones, 25))
+    fn part1(self) -> PuzzleResult<i64> {
+        Ok(count_stones_after_steps(self.stones, 25))
     }
 
-    fn part2(self) -> Option<i64> {
-        Some(count_stones_after_steps(self.stones, 75))
+    fn part2(self) -> PuzzleResult<i64> {
+        Ok(count_stones_after_steps(self.stones, 75))
     }
 }
@@ -1,38 +1,40 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use crate::{
-    position::{read_map, Position},
-    puzzle::Puzzle,
+    position::{Grid, Position},
+    puzzle::{Puzzle, PuzzleResult},
 };
 
+// The plots are taken out of the map once they belong to a patch
 fn make_patch(
     position: Position,
     patch_type: char,
-    map: &mut HashMap<Position, char>,
+    map: &mut Grid<Option<char>>,
     this_patch: &mut HashSet<Position>,
 ) {
-    let _ = map.remove(&position);
     this_patch.insert(position);
 
     let neighbors = position.neighbors();
 
     for other in neighbors {
-        if Some(&patch_type) == map.get(&other) {
+        if let Some(plot) = map.get_mut(other).filter(|plot| **plot == Some(patch_type)) {
+            *plot = None;
             make_patch(other, patch_type, map, this_patch);
         }
     }
 }
 
-fn make_all_patches(map: &mut HashMap<Position, char>) -> Vec<HashSet<Position>> {
+fn make_all_patches(map: &mut Grid<Option<char>>) -> Vec<HashSet<Position>> {
     let mut patches = Vec::new();
 
-    while !map.is_empty() {
-        let (start_position, patch_type) = map.iter().next().unwrap();
-        let mut this_patch = HashSet::new();
+    for start_position in map.positions() {
+        if let Some(patch_type) = map[start_position].take() {
+            let mut this_patch = HashSet::new();
 
-        make_patch(*start_position, *patch_type, map, &mut this_patch);
+            make_patch(start_position, patch_type, map, &mut this_patch);
 
-        patches.push(this_patch);
+            patches.push(this_patch);
+        }
     }
 
     patches
@@ -89,15 +91,12 @@ fn patch_side_count(patch: &HashSet<Position>) -> i64 {
                 // When there are 2 neighbours, either the neighbours are
                 // parallel, in which case the patch counts for 0 or they make
                 // an angle, in which case it counts for 1 or 2 (see below to
-                // distinguish between these two cases)
-                let dx = (p1.x - p2.x).abs();
-                let dy = (p1.y - p2.y).abs();
-
-                // If the two neighbours are parallel, either dx or dy is 2.
-                // Otherwise they should be both one
-                assert!((dx == 2 && dy == 0) || (dx == 0 && dy == 2) || (dx == dy && dx == 1));
+                // distinguish between these two cases). The two neighbours
+                // are always at a Manhattan distance of 2, but only parallel
+                // neighbours are also at a Chebyshev distance of 2
+                assert_eq!(p1.manhattan_distance(*p2), 2);
 
-                if dx == 2 || dy == 2 {
+                if p1.chebyshev_distance(*p2) == 2 {
                     0
                 } else {
                     let dp1 = *p1 - patch_element;
@@ -184,26 +183,55 @@ fn patch_side_count(patch: &HashSet<Position>) -> i64 {
     number_of_angles
 }
 
+#[derive(Clone)]
 pub struct Day12 {
-    map: HashMap<Position, char>,
+    map: Grid<Option<char>>,
 }
 
+register_puzzle!(Day12, year = 2024, day = 12, title = "Garden Groups");
+
+examples![
+    small {
+        input: "\
+AAAA
+BBCD
+BBCC
+EEEC
+",
+        part1: 140,
+        part2: 80,
+    },
+    larger {
+        input: "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+",
+        part1: 1930,
+        part2: 1206,
+    },
+];
+
 impl Puzzle for Day12 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
-        let mut map = HashMap::new();
-
-        read_map(input, |pos, c| {
-            let _ = map.insert(pos, c);
-        });
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        let map = Grid::from_map(input, |_, c| Ok(Some(c)))?;
 
-        Some(Day12 { map })
+        Ok(Day12 { map })
     }
 
-    fn part1(mut self) -> Option<i64> {
+    fn part1(mut self) -> PuzzleResult<Self::Output1> {
         let patches = make_all_patches(&mut self.map);
-        Some(
+        Ok(
             patches
                 .into_iter()
                 .map(|patch| patch_area(&patch) * patch_perimeter(&patch))
@@ -211,10 +239,10 @@ impl Puzzle for Day12 {
         )
     }
 
-    fn part2(mut self) -> Option<i64> {
+    fn part2(mut self) -> PuzzleResult<Self::Output2> {
         let patches = make_all_patches(&mut self.map);
 
-        Some(
+        Ok(
             patches
                 .into_iter()
                 .map(|patch| patch_area(&patch) * patch_side_count(&patch))
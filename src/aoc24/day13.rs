@@ -1,6 +1,9 @@
 use regex::Regex;
 
-use crate::{position::Position, puzzle::Puzzle};
+use crate::{
+    position::Position,
+    puzzle::{Context as _, Puzzle, PuzzleResult},
+};
 
 #[derive(Debug)]
 struct Solution {
@@ -14,7 +17,7 @@ impl Solution {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Machine {
     button_a: Position,
     button_b: Position,
@@ -47,14 +50,41 @@ impl Machine {
     }
 }
 
+#[derive(Clone)]
 pub struct Day13 {
     machine_list: Vec<Machine>,
 }
 
+register_puzzle!(Day13, year = 2024, day = 13, title = "Claw Contraption");
+
+examples![
+    claw_machines {
+        input: "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+",
+        part1: 480,
+    },
+];
+
 impl Puzzle for Day13 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let button_regex = Regex::new(r"Button (A|B): X\+(?<x>\d+), Y\+(?<y>\d+)").unwrap();
         let prize_regex = Regex::new(r"Prize: X=(?<x>\d+), Y=(?<y>\d+)").unwrap();
 
@@ -63,35 +93,43 @@ impl Puzzle for Day13 {
         for block in input.split("\n\n") {
             let mut lines = block.lines();
 
-            let button_a_spec = button_regex.captures(lines.next()?)?;
-            let button_b_spec = button_regex.captures(lines.next()?)?;
-            let prize_spec = prize_regex.captures(lines.next()?)?;
+            let mut read_position = |regex: &Regex, expected: &str| {
+                let line = lines
+                    .next()
+                    .context_at(input, block, format!("Missing the {expected} line"))?;
+                let spec = regex
+                    .captures(line)
+                    .context_at(input, line, format!("Expected the {expected} line"))?;
+                let coordinate = |name: &str| {
+                    let value = spec.name(name).unwrap().as_str();
+
+                    value
+                        .parse::<i64>()
+                        .context_at(input, value, "Coordinate out of range")
+                };
+
+                Ok(Position {
+                    x: coordinate("x")?,
+                    y: coordinate("y")?,
+                })
+            };
 
             let machine = Machine {
-                button_a: Position {
-                    x: button_a_spec["x"].parse::<i64>().unwrap(),
-                    y: button_a_spec["y"].parse::<i64>().unwrap(),
-                },
-                button_b: Position {
-                    x: button_b_spec["x"].parse::<i64>().unwrap(),
-                    y: button_b_spec["y"].parse::<i64>().unwrap(),
-                },
-                prize: Position {
-                    x: prize_spec["x"].parse::<i64>().unwrap(),
-                    y: prize_spec["y"].parse::<i64>().unwrap(),
-                },
+                button_a: read_position(&button_regex, "button A")?,
+                button_b: read_position(&button_regex, "button B")?,
+                prize: read_position(&prize_regex, "prize")?,
             };
 
             machines.push(machine);
         }
 
-        Some(Day13 {
+        Ok(Day13 {
             machine_list: machines,
         })
     }
 
-    fn part1(self) -> Option<i64> {
-        Some(
+    fn part1(self) -> PuzzleResult<i64> {
+        Ok(
             self.machine_list
                 .into_iter()
                 .filter_map(|machine| machine.solve())
@@ -100,13 +138,13 @@ impl Puzzle for Day13 {
         )
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         let offset = Position {
             x: 10000000000000,
             y: 10000000000000,
         };
 
-        Some(
+        Ok(
             self.machine_list
                 .into_iter()
                 .map(|machine| Machine {
@@ -2,10 +2,12 @@ use std::cmp::Ordering;
 
 use regex::Regex;
 
-use crate::{position::Position, puzzle::Puzzle};
+use crate::{
+    position::{Bounds, Position},
+    puzzle::{Context as _, Puzzle, PuzzleResult},
+};
 
-const FLOOR_WIDTH: i64 = 101;
-const FLOOR_HEIGHT: i64 = 103;
+const FLOOR: Bounds = Bounds::with_size(101, 103);
 
 enum Quadrant {
     NorthWest,
@@ -16,8 +18,8 @@ enum Quadrant {
 
 fn quadrant(position: Position) -> Option<Quadrant> {
     match (
-        position.x.cmp(&(FLOOR_WIDTH / 2)),
-        position.y.cmp(&(FLOOR_HEIGHT / 2)),
+        position.x.cmp(&(FLOOR.min.x + FLOOR.width() / 2)),
+        position.y.cmp(&(FLOOR.min.y + FLOOR.height() / 2)),
     ) {
         (Ordering::Equal, _) | (_, Ordering::Equal) => None,
         (Ordering::Less, Ordering::Less) => Some(Quadrant::NorthWest),
@@ -81,54 +83,69 @@ struct Robot {
 
 impl Robot {
     fn position_at(self, time: i64) -> Position {
-        Position {
-            x: (self.position.x + time * self.velocity.x).rem_euclid(FLOOR_WIDTH),
-            y: (self.position.y + time * self.velocity.y).rem_euclid(FLOOR_HEIGHT),
-        }
+        FLOOR.wrap(self.position + self.velocity * time)
     }
 }
 
+#[derive(Clone)]
 pub struct Day14 {
     robots: Vec<Robot>,
 }
 
+register_puzzle!(Day14, year = 2024, day = 14, title = "Restroom Redoubt");
+
+// The example floor is 11 tiles wide and 7 tiles tall, which does not match
+// the hard-coded size of the real floor
+examples![];
+
 impl Puzzle for Day14 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let re = Regex::new(r"p=(?<x>\-?\d+),(?<y>\-?\d+) v=(?<vx>\-?\d+),(?<vy>\-?\d+)").unwrap();
 
-        Some(Day14 {
+        Ok(Day14 {
             robots: input
                 .lines()
                 .map(|line| {
-                    let captures = re.captures(line)?;
-                    Some(Robot {
+                    let captures = re
+                        .captures(line)
+                        .context_at(input, line, "Expected a robot of the form p=x,y v=x,y")?;
+                    let coordinate = |name: &str| {
+                        let value = captures.name(name).unwrap().as_str();
+
+                        value
+                            .parse::<i64>()
+                            .context_at(input, value, "Coordinate out of range")
+                    };
+
+                    Ok(Robot {
                         position: Position {
-                            x: captures["x"].parse().unwrap(),
-                            y: captures["y"].parse().unwrap(),
+                            x: coordinate("x")?,
+                            y: coordinate("y")?,
                         },
                         velocity: Position {
-                            x: captures["vx"].parse().unwrap(),
-                            y: captures["vy"].parse().unwrap(),
+                            x: coordinate("vx")?,
+                            y: coordinate("vy")?,
                         },
                     })
                 })
-                .collect::<Option<Vec<_>>>()?,
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let final_robot_positions = self
             .robots
             .into_iter()
             .map(|robot| robot.position_at(100))
             .collect::<Vec<_>>();
 
-        Some(quadrant_count(&final_robot_positions).safety_score())
+        Ok(quadrant_count(&final_robot_positions).safety_score())
     }
 
-    fn part2(self) -> Option<i64> {
+    fn part2(self) -> PuzzleResult<i64> {
         // This solution is based on this reddit thread:
         // https://www.reddit.com/r/adventofcode/comments/1he0asr/2024_day_14_part_2_why_have_fun_with_image/
         //
@@ -147,7 +164,7 @@ impl Puzzle for Day14 {
 
         // For all time, produce a vector with 1) the current time, 2) the
         // variance of the xs, 3) the variance of the ys
-        let all_variances = (0..FLOOR_HEIGHT.max(FLOOR_WIDTH))
+        let all_variances = (0..FLOOR.height().max(FLOOR.width()))
             .map(|time| {
                 let robot_positions = self
                     .robots
@@ -179,16 +196,16 @@ impl Puzzle for Day14 {
 
         // We know that the offset we are looking for, t, is of the form:
         //
-        //     t % FLOOR_WIDTH  = n_x
-        //     t % FLOOR_HEIGHT = n_y
+        //     t % FLOOR.width()  = n_x
+        //     t % FLOOR.height() = n_y
         //
         // We'll use a brute-force approach by starting at t = n_x and just
         // adding the width until we get the result we want
         let mut t = n_x;
-        while t % FLOOR_HEIGHT != n_y {
-            t += FLOOR_WIDTH;
+        while t % FLOOR.height() != n_y {
+            t += FLOOR.width();
         }
 
-        Some(t)
+        Ok(t)
     }
 }
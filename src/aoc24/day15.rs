@@ -2,9 +2,10 @@ use std::collections::{HashMap, HashSet};
 
 use crate::{
     position::{Direction, Position},
-    puzzle::Puzzle,
+    puzzle::{Context as _, Puzzle, PuzzleResult},
 };
 
+#[derive(Clone)]
 enum Object {
     Wall,
     Crate,
@@ -66,7 +67,7 @@ fn execute(direction: Direction, objects: &mut HashMap<Position, Object>, robot:
     if can_move {
         let dv = direction.delta();
 
-        *robot = *robot + dv;
+        *robot += dv;
         for (neighbour, _) in block.iter() {
             let _ = objects.remove(&neighbour);
         }
@@ -90,6 +91,7 @@ fn compute_score(objects: HashMap<Position, Object>) -> i64 {
         .sum()
 }
 
+#[derive(Clone)]
 pub struct Day15 {
     objects: HashMap<Position, Object>,
 
@@ -136,13 +138,63 @@ impl Day15 {
     }
 }
 
+register_puzzle!(Day15, year = 2024, day = 15, title = "Warehouse Woes");
+
+examples![
+    small {
+        input: "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+",
+        part1: 2028,
+    },
+    larger {
+        input: "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+",
+        part1: 10092,
+        part2: 9021,
+    },
+];
+
 impl Puzzle for Day15 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut split_input = input.split("\n\n");
-        let grid = split_input.next()?;
-        let instructions = split_input.next()?;
+        let grid = split_input.next().context("Missing the warehouse map")?;
+        let instructions = split_input
+            .next()
+            .context("Expected the moves to be separated from the map by an empty line")?;
 
         let mut objects = HashMap::new();
         let mut robot = None;
@@ -169,7 +221,7 @@ impl Puzzle for Day15 {
             }
         }
 
-        let initial_robot_position = robot?;
+        let initial_robot_position = robot.context("There is no robot on the map")?;
 
         let instruction_tape = instructions
             .chars()
@@ -182,14 +234,14 @@ impl Puzzle for Day15 {
             })
             .collect::<Vec<_>>();
 
-        Some(Day15 {
+        Ok(Day15 {
             objects,
             initial_robot_position,
             instruction_tape,
         })
     }
 
-    fn part1(self) -> Option<i64> {
+    fn part1(self) -> PuzzleResult<i64> {
         let mut robot = self.initial_robot_position;
         let mut objects = self.objects;
 
@@ -197,10 +249,10 @@ impl Puzzle for Day15 {
             execute(instruction, &mut objects, &mut robot);
         }
 
-        Some(compute_score(objects))
+        Ok(compute_score(objects))
     }
 
-    fn part2(mut self) -> Option<i64> {
+    fn part2(mut self) -> PuzzleResult<i64> {
         self = self.expand();
 
         let mut robot = self.initial_robot_position;
@@ -210,6 +262,6 @@ impl Puzzle for Day15 {
             execute(instruction, &mut objects, &mut robot);
         }
 
-        Some(compute_score(objects))
+        Ok(compute_score(objects))
     }
 }
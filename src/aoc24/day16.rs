@@ -1,8 +1,9 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashSet;
 
 use crate::{
     position::{read_map, Direction, Position},
-    puzzle::Puzzle,
+    puzzle::{Context as _, Puzzle, PuzzleResult},
+    search::{dijkstra, Search},
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -37,69 +38,82 @@ impl Deer {
             ),
         ]
     }
-
-    fn previous(self) -> [(Deer, i64); 3] {
-        [
-            (
-                Deer {
-                    position: self.position - self.direction.delta(),
-                    direction: self.direction,
-                },
-                -1,
-            ),
-            (
-                Deer {
-                    position: self.position,
-                    direction: self.direction.next_clockwise(),
-                },
-                -1_000,
-            ),
-            (
-                Deer {
-                    position: self.position,
-                    direction: self.direction.next_anticlockwise(),
-                },
-                -1_000,
-            ),
-        ]
-    }
 }
 
-fn explore(walls: &HashSet<Position>, deer: Deer) -> HashMap<Deer, i64> {
-    let mut visited = HashMap::from([(deer, 0)]);
-    let mut positions_left_to_visit = VecDeque::from(deer.next());
-
-    while let Some((deer, score)) = positions_left_to_visit.pop_front() {
-        if !walls.contains(&deer.position) {
-            match visited.get(&deer).cloned() {
-                Some(old_score) if old_score <= score => {
-                    // Nothing to do
-                }
-                None | Some(_) => {
-                    // This tile was never visited going that direction or
-                    // its score needs to be updated
-                    visited.insert(deer, score);
-                    // Instruct to revisit the neighbours
-                    let next = deer.next().map(|(deer, cost)| (deer, score + cost));
-                    positions_left_to_visit.extend(next);
-                }
-            }
-        }
-    }
-
-    visited
+// Explores until the end tile is reached, whichever the direction
+fn explore(walls: &HashSet<Position>, deer: Deer, end: Position) -> Search<Deer> {
+    dijkstra(
+        deer,
+        |deer| {
+            deer.next()
+                .into_iter()
+                .filter(|(next, _)| !walls.contains(&next.position))
+        },
+        |deer| deer.position == end,
+    )
 }
 
+#[derive(Clone)]
 pub struct Day16 {
     walls: HashSet<Position>,
     deer: Deer,
     end: Position,
 }
 
+register_puzzle!(Day16, year = 2024, day = 16, title = "Reindeer Maze");
+
+examples![
+    first_maze {
+        input: "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+",
+        part1: 7036,
+        part2: 45,
+    },
+    second_maze {
+        input: "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+",
+        part1: 11048,
+        part2: 64,
+    },
+];
+
 impl Puzzle for Day16 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
     
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut walls = HashSet::new();
         let mut deer = None;
         let mut end = None;
@@ -120,68 +134,31 @@ impl Puzzle for Day16 {
             _ => (),
         });
 
-        let deer = deer?;
-        let end = end?;
+        let deer = deer.context("There is no start tile")?;
+        let end = end.context("There is no end tile")?;
 
-        Some(Day16 { walls, deer, end })
+        Ok(Day16 { walls, deer, end })
     }
 
-    fn part1(self) -> Option<i64> {
-        let walls = self.walls;
-
-        let visited = explore(&walls, self.deer);
-
-        let best_score = Direction::ALL
-            .into_iter()
-            .filter_map(|dir| {
-                visited.get(&Deer {
-                    position: self.end,
-                    direction: dir,
-                })
-            })
-            .min()
-            .cloned()?;
-
-        Some(best_score)
+    fn part1(self) -> PuzzleResult<i64> {
+        explore(&self.walls, self.deer, self.end)
+            .goal_distance()
+            .context("The end tile cannot be reached")
     }
 
-    fn part2(self) -> Option<i64> {
-        let visited = explore(&self.walls, self.deer);
-        let (final_deer_direction, best_score) = Direction::ALL
-            .into_iter()
-            .filter_map(|dir| {
-                visited
-                    .get(&Deer {
-                        position: self.end,
-                        direction: dir,
-                    })
-                    .map(|score| (dir, *score))
-            })
-            .min_by(|(_, s1), (_, s2)| s1.cmp(s2))?;
-
-        let deer = Deer {
-            direction: final_deer_direction,
-            position: self.end,
-        };
-
-        let mut best_path = HashSet::from([deer.position]);
+    fn part2(self) -> PuzzleResult<i64> {
+        let search = explore(&self.walls, self.deer, self.end);
+        search
+            .goal_distance()
+            .context("The end tile cannot be reached")?;
 
-        // The tiles on the best path are all the tiles that are reachable
-        // (backward) from the end tile with a decreasing score
-        let mut left_to_visit = VecDeque::from([(deer, best_score)]);
-
-        while let Some((deer, score)) = left_to_visit.pop_front() {
-            best_path.insert(deer.position);
-            let previous_neighbours = deer.previous();
-            let previous_neighbours_on_the_best_path = previous_neighbours
-                .into_iter()
-                .map(|(other, delta_score)| (other, score + delta_score))
-                .filter(|(other, expected_score)| {
-                    visited.get(&other).cloned() == Some(*expected_score)
-                });
-            left_to_visit.extend(previous_neighbours_on_the_best_path);
-        }
+        // The deer may go through a tile in several directions
+        let best_path = search
+            .on_optimal_paths()
+            .into_iter()
+            .map(|deer| deer.position)
+            .collect::<HashSet<_>>();
 
-        Some(best_path.len() as i64)
+        Ok(best_path.len() as i64)
     }
 }
@@ -1,6 +1,6 @@
 use z3::{ast::Ast as _, FuncDecl};
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Register {
@@ -309,43 +309,80 @@ impl Machine {
     }
 }
 
+#[derive(Clone)]
 pub struct Day17 {
     initial_machine: Machine,
 }
 
+register_puzzle!(Day17, year = 2024, day = 17, title = "Chronospatial Computer");
+
+examples![
+    program {
+        input: "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+",
+        part1: "4,6,3,5,6,3,5,2,1,0",
+    },
+    quine {
+        input: "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+",
+        part2: 117440,
+    },
+];
+
 impl Puzzle for Day17 {
-    type Output = String;
+    type Output1 = String;
+    type Output2 = i64;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut lines = input.lines();
 
         let mut registers = [0; 3];
 
         for register in registers.iter_mut() {
-            let line = lines.next()?;
+            let line = lines.next().context("Missing a register")?;
             let mut words = line.split_whitespace();
-            let _ = words.next()?; // Register
-            let _ = words.next()?; // A: or B: or C:
-            let value = words.next()?;
-
-            *register = value.parse::<i64>().ok()?;
+            let _ = words.next(); // Register
+            let _ = words.next(); // A: or B: or C:
+            let value = words
+                .next()
+                .context_at(input, line, "Expected a register of the form Register A: value")?;
+
+            *register = value
+                .parse::<i64>()
+                .context_at(input, value, "Not a register value")?;
         }
 
-        let _ = lines.next()?; // Skip an empty line
-        let program_line = lines.next()?;
+        let _ = lines.next().context("Missing the program")?; // Skip an empty line
+        let program_line = lines.next().context("Missing the program")?;
 
         let mut words = program_line.split_whitespace();
-        let _ = words.next()?; // Program:
-        let tape = words.next()?;
+        let _ = words.next(); // Program:
+        let tape_words = words
+            .next()
+            .context_at(input, program_line, "Expected a program of the form Program: tape")?;
 
-        let tape = tape
+        let tape = tape_words
             .split(',')
-            .map(|instr| instr.parse::<u8>().ok())
-            .collect::<Option<Vec<_>>>()?;
+            .map(|instr| instr.parse::<u8>().context_at(input, instr, "Not a 3-bit number"))
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
-        let (block, output_of_interest) = decode_tape(&tape)?;
+        let (block, output_of_interest) = decode_tape(&tape).context_at(
+            input,
+            tape_words,
+            "Only programs of the form \"while { block; out }\" are supported",
+        )?;
 
-        Some(Day17 {
+        Ok(Day17 {
             initial_machine: Machine {
                 registers,
                 block,
@@ -355,17 +392,17 @@ impl Puzzle for Day17 {
         })
     }
 
-    fn part1(mut self) -> Option<Self::Output> {
+    fn part1(mut self) -> PuzzleResult<Self::Output1> {
         let output = self.initial_machine.run();
         let string_list = output
             .into_iter()
             .map(|value| format!("{value}"))
             .collect::<Vec<_>>();
 
-        Some(string_list.join(","))
+        Ok(string_list.join(","))
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let cfg = z3::Config::new();
         let ctx = z3::Context::new(&cfg);
 
@@ -374,8 +411,9 @@ impl Puzzle for Day17 {
             self.initial_machine.block,
             self.initial_machine.output_of_interest,
             self.initial_machine.target_output,
-        )?;
+        )
+        .context("No initial value of register A makes the program output itself")?;
 
-        Some(format!("{answer}"))
+        Ok(answer)
     }
 }
@@ -1,84 +1,77 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-
-use crate::{position::Position, puzzle::Puzzle};
-
-const GRID_SIZE: i64 = 71;
-
-fn explore(obstacles: &HashSet<Position>) -> HashMap<Position, i64> {
-    let initial_position = Position { x: 0, y: 0 };
-    let mut visited = HashMap::from([(initial_position, 0)]);
-
-    let mut positions_left_to_visit =
-        VecDeque::from(initial_position.neighbors().map(|pos| (pos, 1)));
-
-    while let Some((pos, score)) = positions_left_to_visit.pop_front() {
-        if !obstacles.contains(&pos)
-            && pos.x >= 0
-            && pos.y >= 0
-            && pos.x < GRID_SIZE
-            && pos.y < GRID_SIZE
-        {
-            match visited.get(&pos).cloned() {
-                Some(old_score) if old_score <= score => {
-                    // Nothing to do
-                }
-                None | Some(_) => {
-                    // This tile was never visited going that direction or
-                    // its score needs to be updated
-                    visited.insert(pos, score);
-                    // Instruct to revisit the neighbours
-                    let next = pos.neighbors().map(|next| (next, score + 1));
-                    positions_left_to_visit.extend(next);
-                }
-            }
-        }
-    }
-
-    visited
+use std::collections::HashSet;
+
+use crate::{
+    position::{Bounds, Position},
+    puzzle::{Context as _, Puzzle, PuzzleResult},
+    search::a_star,
+};
+
+const MEMORY: Bounds = Bounds::with_size(71, 71);
+
+const EXIT: Position = MEMORY.max;
+
+// The length of the shortest path to the exit, guided by the distance to the
+// exit as if there were no obstacles
+fn shortest_path(obstacles: &HashSet<Position>) -> Option<i64> {
+    a_star(
+        MEMORY.min,
+        |pos| {
+            pos.neighbors()
+                .into_iter()
+                .filter(|&next| MEMORY.contains(next) && !obstacles.contains(&next))
+                .map(|next| (next, 1))
+        },
+        |pos| pos.manhattan_distance(EXIT),
+        |&pos| pos == EXIT,
+    )
+    .distance(&EXIT)
 }
 
+#[derive(Clone)]
 pub struct Day18 {
     coordinates: Vec<Position>,
 }
 
+register_puzzle!(Day18, year = 2024, day = 18, title = "RAM Run");
+
+// The example memory space is 7 by 7 and only the first 12 bytes fall, which
+// does not match the hard-coded size of the real memory space
+examples![];
+
 impl Puzzle for Day18 {
-    type Output = String;
+    type Output1 = i64;
+    type Output2 = String;
 
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day18 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day18 {
             coordinates: input
                 .lines()
                 .map(|word| {
-                    let (x, y) = word.split_once(',')?;
-                    let x = x.parse::<i64>().ok()?;
-                    let y = y.parse::<i64>().ok()?;
+                    let (x, y) = word
+                        .split_once(',')
+                        .context_at(input, word, "Expected coordinates of the form x,y")?;
+                    let x = x.parse::<i64>().context_at(input, x, "Not a coordinate")?;
+                    let y = y.parse::<i64>().context_at(input, y, "Not a coordinate")?;
 
-                    Some(Position { x, y })
+                    Ok(Position { x, y })
                 })
-                .collect::<Option<Vec<_>>>()?,
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<Self::Output> {
+    fn part1(self) -> PuzzleResult<Self::Output1> {
         let obstacles = self
             .coordinates
             .into_iter()
             .take(1_024)
             .collect::<HashSet<_>>();
 
-        let results = explore(&obstacles);
-
-        let answer = results
-            .get(&Position {
-                x: GRID_SIZE - 1,
-                y: GRID_SIZE - 1,
-            })
-            .cloned()?;
+        let answer = shortest_path(&obstacles).context("The exit cannot be reached")?;
 
-        Some(format!("{answer}"))
+        Ok(answer)
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let mut start = 0; 
         let mut end = self.coordinates.len() - 1; 
 
@@ -91,11 +84,7 @@ impl Puzzle for Day18 {
             // from start and end, otherwise we return the obstacle just after
             // the one we are looking for
             let obstacles = HashSet::from_iter(self.coordinates.iter().cloned().take(mid_point));
-            let results = explore(&obstacles);
-            let can_cross = results.get(&Position {
-                    x: GRID_SIZE - 1,
-                    y: GRID_SIZE - 1,
-                }).is_some(); 
+            let can_cross = shortest_path(&obstacles).is_some();
 
             if can_cross {
                 start = mid_point + 1;
@@ -106,6 +95,6 @@ impl Puzzle for Day18 {
 
         let obstacle = self.coordinates[start]; 
 
-        Some(format!("{},{}", obstacle.x, obstacle.y))
+        Ok(format!("{},{}", obstacle.x, obstacle.y))
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
 struct Trie {
     is_word: bool,
@@ -103,49 +103,71 @@ impl Color {
     }
 }
 
+#[derive(Clone)]
 pub struct Day19 {
     towels: Vec<Vec<Color>>,
 
     patterns: Vec<Vec<Color>>,
 }
 
+register_puzzle!(Day19, year = 2024, day = 19, title = "Linen Layout");
+
+examples![
+    towels {
+        input: "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+",
+        part1: 6,
+        part2: 16,
+    },
+];
+
 impl Puzzle for Day19 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        let (towels, patterns) = input
+            .split_once("\n\n")
+            .context("Expected the towels and the patterns to be separated by an empty line")?;
 
-    fn parse(input: &str) -> Option<Self> {
-        let (towels, patterns) = input.split_once("\n\n")?;
+        let colors = |word: &str| {
+            word.chars()
+                .map(Color::from_char)
+                .collect::<Option<Vec<_>>>()
+                .context_at(input, word, "Colors must be one of w, u, b, r or g")
+        };
 
         let towels = towels
             .split(", ")
-            .map(|towel| {
-                towel
-                    .chars()
-                    .map(Color::from_char)
-                    .collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()?;
+            .map(colors)
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
         let patterns = patterns
             .lines()
-            .map(|pattern| {
-                pattern
-                    .chars()
-                    .map(Color::from_char)
-                    .collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()?;
-
-        Some(Day19 { towels, patterns })
+            .map(colors)
+            .collect::<PuzzleResult<Vec<_>>>()?;
+
+        Ok(Day19 { towels, patterns })
     }
 
-    fn part1(self) -> Option<Self::Output> {
+    fn part1(self) -> PuzzleResult<Self::Output1> {
         let mut trie = Trie::new();
 
         for towel in self.towels.into_iter() {
             trie.add_pattern(&towel);
         }
 
-        Some(
+        Ok(
             self.patterns
                 .into_iter()
                 .filter(|pattern| trie.check_pattern(&pattern) > 0)
@@ -153,7 +175,7 @@ impl Puzzle for Day19 {
         )
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let mut trie = Trie::new();
 
         for towel in self.towels.into_iter() {
@@ -163,7 +185,7 @@ impl Puzzle for Day19 {
         let mut patterns = self.patterns;
         patterns.sort_by(|p1, p2| p1.len().cmp(&p2.len()));
 
-        Some(
+        Ok(
             patterns
                 .into_iter()
                 .map(|pattern| trie.check_pattern(&pattern))
@@ -1,34 +1,12 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     position::{read_map, Position},
-    puzzle::Puzzle,
+    puzzle::{Context as _, Puzzle, PuzzleResult},
+    search::bfs,
 };
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct PathPoint {
-    pos: Position,
-    score: i64,
-}
-
-// The priority queue depends on `Ord`.
-// Explicitly implement the trait so the queue becomes a min-heap
-// instead of a max-heap.
-impl PartialOrd for PathPoint {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for PathPoint {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other
-            .score
-            .cmp(&self.score)
-            .then_with(|| self.pos.cmp(&other.pos))
-    }
-}
-
+#[derive(Clone)]
 pub struct Day20 {
     start: Position,
     end: Position,
@@ -36,26 +14,10 @@ pub struct Day20 {
 }
 
 fn neighbours_at_a_distance(position: Position, max_distance: usize) -> HashMap<Position, i64> {
-    let mut neighbours = HashMap::new();
-
-    let mut to_visit = Vec::from([position]);
-
-    for distance in 1..=max_distance {
-        let mut to_visit_next = Vec::new();
-
-        while let Some(pos) = to_visit.pop() {
-            for other in pos.neighbors() {
-                if !neighbours.contains_key(&other) {
-                    neighbours.insert(other, distance as i64);
-                    to_visit_next.push(other);
-                }
-            }
-        }
-
-        to_visit = to_visit_next;
-    }
-
-    neighbours
+    position
+        .within_distance(max_distance as i64)
+        .map(|other| (other, position.manhattan_distance(other)))
+        .collect()
 }
 
 fn find_shortcuts_from_current_position(
@@ -97,52 +59,35 @@ fn find_all_shortcuts(max_cheat_duration: usize, path: &[Position]) -> Vec<i64>
     shortcuts
 }
 
-fn find_path(start: Position, end: Position, obstacles: &HashSet<Position>) -> Vec<Position> {
-    let mut unvisited_nodes = BinaryHeap::from([PathPoint {
-        pos: start,
-        score: 0,
-    }]);
-    let mut visited_nodes = HashMap::new();
-
-    while let Some(PathPoint { pos, score }) = unvisited_nodes.pop() {
-        let neighbours = pos
-            .neighbors()
-            .into_iter()
-            .filter(|other| !obstacles.contains(&other))
-            .filter(|other| !visited_nodes.contains_key(other))
-            .map(|other| PathPoint {
-                pos: other,
-                score: score + 1,
-            });
-
-        unvisited_nodes.extend(neighbours);
-
-        visited_nodes.insert(pos, score);
-    }
-
-    let mut path_heap = BinaryHeap::from_iter(
-        visited_nodes
-            .into_iter()
-            .map(|(pos, score)| PathPoint { pos, score }),
-    );
-
-    let mut path = Vec::new();
-
-    while let Some(PathPoint { pos, .. }) = path_heap.pop() {
-        path.push(pos);
+// The racetrack is a single path, which also is the shortest one
+fn find_path(
+    start: Position,
+    end: Position,
+    obstacles: &HashSet<Position>,
+) -> Option<Vec<Position>> {
+    bfs(
+        start,
+        |pos| {
+            pos.neighbors()
+                .into_iter()
+                .filter(|other| !obstacles.contains(other))
+        },
+        |&pos| pos == end,
+    )
+    .path()
+}
 
-        if pos == end {
-            break;
-        }
-    }
+register_puzzle!(Day20, year = 2024, day = 20, title = "Race Condition");
 
-    path
-}
+// The example has no cheat saving at least 100 picoseconds, so all it would
+// check is that there is none
+examples![];
 
 impl Puzzle for Day20 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut start = None;
         let mut end = None;
         let mut obstacles = HashSet::new();
@@ -156,26 +101,28 @@ impl Puzzle for Day20 {
             _ => (),
         });
 
-        Some(Day20 {
-            start: start?,
-            end: end?,
+        Ok(Day20 {
+            start: start.context("There is no start position")?,
+            end: end.context("There is no end position")?,
             obstacles,
         })
     }
 
-    fn part1(self) -> Option<Self::Output> {
-        let path = find_path(self.start, self.end, &self.obstacles);
+    fn part1(self) -> PuzzleResult<Self::Output1> {
+        let path = find_path(self.start, self.end, &self.obstacles)
+            .context("The end cannot be reached")?;
 
         let shortcuts = find_all_shortcuts(2, &path);
 
-        Some(shortcuts.into_iter().filter(|value| *value >= 100).count() as i64)
+        Ok(shortcuts.into_iter().filter(|value| *value >= 100).count() as i64)
     }
 
-    fn part2(self) -> Option<Self::Output> {
-        let path = find_path(self.start, self.end, &self.obstacles);
+    fn part2(self) -> PuzzleResult<Self::Output2> {
+        let path = find_path(self.start, self.end, &self.obstacles)
+            .context("The end cannot be reached")?;
 
         let shortcuts = find_all_shortcuts(20, &path);
 
-        Some(shortcuts.into_iter().filter(|value| *value >= 100).count() as i64)
+        Ok(shortcuts.into_iter().filter(|value| *value >= 100).count() as i64)
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::{
     position::{Direction, Position},
-    puzzle::Puzzle,
+    puzzle::{Context as _, Puzzle, PuzzleResult},
 };
 
 const NUMERIC_KEYPAD: [Position; 11] = [
@@ -76,7 +76,7 @@ fn move_to(board: &[Position], start: Position, end: Position) -> Vec<Direction>
         .filter(|moves| {
             let mut position = start;
             for dir in moves.iter() {
-                position = position + dir.delta();
+                position += dir.delta();
 
                 if !inside_board(board, position) {
                     return false;
@@ -224,14 +224,31 @@ fn sum_door_solution(codes: Vec<Vec<NumericKey>>, robots_involved: usize) -> i64
         .sum()
 }
 
+#[derive(Clone)]
 pub struct Day21 {
     door_codes: Vec<Vec<NumericKey>>,
 }
 
+register_puzzle!(Day21, year = 2024, day = 21, title = "Keypad Conundrum");
+
+examples![
+    codes {
+        input: "\
+029A
+980A
+179A
+456A
+379A
+",
+        part1: 126384,
+    },
+];
+
 impl Puzzle for Day21 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let door_codes = input
             .lines()
             .map(|line| {
@@ -241,17 +258,18 @@ impl Puzzle for Day21 {
                         _ => Some(NumericKey::Num(c.to_digit(10)?.try_into().ok()?)),
                     })
                     .collect::<Option<Vec<_>>>()
+                    .context_at(input, line, "Door codes are made of digits and A")
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
-        Some(Day21 { door_codes })
+        Ok(Day21 { door_codes })
     }
 
-    fn part1(self) -> Option<Self::Output> {
-        Some(sum_door_solution(self.door_codes, 3))
+    fn part1(self) -> PuzzleResult<Self::Output1> {
+        Ok(sum_door_solution(self.door_codes, 3))
     }
 
-    fn part2(self) -> Option<Self::Output> {
-        Some(sum_door_solution(self.door_codes, 26))
+    fn part2(self) -> PuzzleResult<Self::Output2> {
+        Ok(sum_door_solution(self.door_codes, 26))
     }
 }
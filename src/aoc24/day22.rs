@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
+#[derive(Clone)]
 pub struct Day22 {
     secrets: Vec<u64>,
 }
@@ -91,20 +92,44 @@ fn merge_windows(
     }
 }
 
+register_puzzle!(Day22, year = 2024, day = 22, title = "Monkey Market");
+
+examples![
+    buyers {
+        input: "\
+1
+10
+100
+2024
+",
+        part1: 37327623,
+    },
+    bananas {
+        input: "\
+1
+2
+3
+2024
+",
+        part2: 23,
+    },
+];
+
 impl Puzzle for Day22 {
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
-    fn parse(input: &str) -> Option<Self> {
-        Some(Day22 {
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        Ok(Day22 {
             secrets: input
                 .lines()
-                .map(|line| line.parse::<u64>().ok())
-                .collect::<Option<Vec<_>>>()?,
+                .map(|line| line.parse::<u64>().context_at(input, line, "Not a secret number"))
+                .collect::<PuzzleResult<Vec<_>>>()?,
         })
     }
 
-    fn part1(self) -> Option<Self::Output> {
-        Some(
+    fn part1(self) -> PuzzleResult<Self::Output1> {
+        Ok(
             self.secrets
                 .into_iter()
                 .map(|secret| secrets_iter(secret).nth(2_000).unwrap())
@@ -112,7 +137,7 @@ impl Puzzle for Day22 {
         )
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let mut bananas_per_window = HashMap::new();
 
         for secret in self.secrets.into_iter() {
@@ -127,6 +152,9 @@ impl Puzzle for Day22 {
             merge_windows(&mut bananas_per_window, window);
         }
 
-        bananas_per_window.into_values().max()
+        bananas_per_window
+            .into_values()
+            .max()
+            .context("There are no buyers")
     }
 }
@@ -2,7 +2,7 @@ use std::collections::{BTreeSet, HashMap};
 
 use regex::Regex;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Computer {
@@ -102,14 +102,59 @@ fn find_largest_connected_set(links: &HashMap<Computer, BTreeSet<Computer>>) ->
     }
 }
 
+#[derive(Clone)]
 pub struct Day23 {
     links: Vec<[Computer; 2]>,
 }
 
+register_puzzle!(Day23, year = 2024, day = 23, title = "LAN Party");
+
+examples![
+    network {
+        input: "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+",
+        part1: 7,
+        part2: "co,de,ka,ta",
+    },
+];
+
 impl Puzzle for Day23 {
-    type Output = String;
+    type Output1 = usize;
+    type Output2 = String;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let regex = Regex::new(r"(?<left>[a-z]{2})-(?<right>[a-z]{2})").unwrap();
 
         let links = input
@@ -131,13 +176,14 @@ impl Puzzle for Day23 {
 
                     Some([left, right])
                 })
+                .context_at(input, line, "Expected a link of the form ab-cd")
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<PuzzleResult<Vec<_>>>()?;
 
-        Some(Day23 { links })
+        Ok(Day23 { links })
     }
 
-    fn part1(self) -> Option<Self::Output> {
+    fn part1(self) -> PuzzleResult<Self::Output1> {
         let link_set = make_link_set(&self.links);
 
         let count = makes_sets_of_size(&link_set, 3)
@@ -148,10 +194,10 @@ impl Puzzle for Day23 {
             })
             .count();
 
-        Some(format!("{count}"))
+        Ok(count)
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         let link_set = make_link_set(&self.links);
 
         let largest_set = find_largest_connected_set(&link_set);
@@ -162,6 +208,6 @@ impl Puzzle for Day23 {
             .collect::<Vec<_>>()
             .join(",");
 
-        Some(password)
+        Ok(password)
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleError, PuzzleResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Register {
@@ -84,13 +84,15 @@ fn value_of(
     rules: &HashMap<Wire, Connection>,
     known_values: &mut HashMap<Wire, bool>,
     wire: Wire,
-) -> bool {
+) -> PuzzleResult<bool> {
     match known_values.get(&wire) {
-        Some(value) => *value,
+        Some(value) => Ok(*value),
         None => {
-            let rule = rules.get(&wire).expect("Missing rule :(");
-            let fst = value_of(rules, known_values, rule.fst);
-            let snd = value_of(rules, known_values, rule.snd);
+            let rule = rules
+                .get(&wire)
+                .context(format!("No gate outputs to wire {}", wire.show()))?;
+            let fst = value_of(rules, known_values, rule.fst)?;
+            let snd = value_of(rules, known_values, rule.snd)?;
 
             let result = match rule.gate {
                 Gate::And => fst && snd,
@@ -100,18 +102,21 @@ fn value_of(
 
             known_values.insert(wire, result);
 
-            result
+            Ok(result)
         }
     }
 }
 
-fn get_zs(rules: &HashMap<Wire, Connection>, known_values: &mut HashMap<Wire, bool>) -> u64 {
+fn get_zs(
+    rules: &HashMap<Wire, Connection>,
+    known_values: &mut HashMap<Wire, bool>,
+) -> PuzzleResult<u64> {
     let mut result = 0;
 
     for &wire in rules.keys() {
         match wire {
             Wire::Numbered(Register::Z, n) => {
-                let on = value_of(rules, known_values, wire);
+                let on = value_of(rules, known_values, wire)?;
 
                 if on {
                     result |= 1_u64 << n;
@@ -121,7 +126,7 @@ fn get_zs(rules: &HashMap<Wire, Connection>, known_values: &mut HashMap<Wire, bo
         }
     }
 
-    result
+    Ok(result)
 }
 
 fn is_input_of_gate(
@@ -212,64 +217,158 @@ fn extract_odd_gates(input_bit_count: u32, connections: &HashMap<Wire, Connectio
         .collect()
 }
 
+#[derive(Clone)]
 pub struct Day24 {
     inputs: HashMap<Wire, bool>,
     connections: HashMap<Wire, Connection>,
 }
 
+register_puzzle!(Day24, year = 2024, day = 24, title = "Crossed Wires");
+
+examples![
+    small {
+        input: "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+",
+        part1: 4,
+    },
+    larger {
+        input: "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+",
+        part1: 2024,
+    },
+];
+
 impl Puzzle for Day24 {
-    type Output = String;
+    type Output1 = u64;
+    type Output2 = String;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let input_regex = Regex::new(r"(?<wire>[a-z0-9]+): (?<value>\d+)").unwrap();
         let connection_regex = Regex::new(
             r"(?<fst>[a-z0-9]+) (?<gate>OR|XOR|AND) (?<snd>[a-z0-9]+) -> (?<output>[a-z0-9]+)",
         )
         .unwrap();
 
-        let (inputs, connections) = input.split_once("\n\n")?;
+        let (inputs, connections) = input
+            .split_once("\n\n")
+            .context("Expected the inputs and the gates to be separated by an empty line")?;
+
+        let wire = |name: &str| {
+            Wire::new(name).context_at(input, name, "Wire names must be 3 characters long")
+        };
 
         let inputs = inputs
             .lines()
             .map(|line| {
-                let capture = input_regex.captures(line)?;
-                let value = capture["value"].parse::<i64>().unwrap();
-
-                Some((Wire::new(&capture["wire"])?, value != 0))
+                let capture = input_regex
+                    .captures(line)
+                    .context_at(input, line, "Expected an input of the form wire: value")?;
+                let value = capture.name("value").unwrap().as_str();
+                let value = value
+                    .parse::<i64>()
+                    .context_at(input, value, "Not a wire value")?;
+
+                Ok((wire(capture.name("wire").unwrap().as_str())?, value != 0))
             })
-            .collect::<Option<HashMap<_, _>>>()?;
+            .collect::<PuzzleResult<HashMap<_, _>>>()?;
 
         let connections = connections
             .lines()
             .map(|line| {
-                let capture = connection_regex.captures(line)?;
-                let fst = Wire::new(&capture["fst"])?;
-                let snd = Wire::new(&capture["snd"])?;
-                let output = Wire::new(&capture["output"])?;
-                let gate = match &capture["gate"] {
+                let capture = connection_regex
+                    .captures(line)
+                    .context_at(input, line, "Expected a gate of the form a OP b -> c")?;
+                let fst = wire(capture.name("fst").unwrap().as_str())?;
+                let snd = wire(capture.name("snd").unwrap().as_str())?;
+                let output = wire(capture.name("output").unwrap().as_str())?;
+                let gate = capture.name("gate").unwrap().as_str();
+                let gate = match gate {
                     "OR" => Gate::Or,
                     "XOR" => Gate::Xor,
                     "AND" => Gate::And,
-                    unknown => panic!("Not a gate {unknown}"),
+                    unknown => {
+                        return Err(PuzzleError::in_input(
+                            input,
+                            gate,
+                            format!("Not a gate {unknown}"),
+                        ))
+                    }
                 };
 
-                Some((output, Connection::new(fst, snd, gate)))
+                Ok((output, Connection::new(fst, snd, gate)))
             })
-            .collect::<Option<HashMap<_, _>>>()?;
+            .collect::<PuzzleResult<HashMap<_, _>>>()?;
 
-        Some(Day24 {
+        Ok(Day24 {
             inputs,
             connections,
         })
     }
 
-    fn part1(self) -> Option<Self::Output> {
+    fn part1(self) -> PuzzleResult<Self::Output1> {
         let mut known_values = self.inputs;
 
-        Some(format!("{}", get_zs(&self.connections, &mut known_values)))
+        get_zs(&self.connections, &mut known_values)
     }
 
-    fn part2(self) -> Option<Self::Output> {
+    fn part2(self) -> PuzzleResult<Self::Output2> {
         // The solution for this part is taken from
         // https://www.reddit.com/r/adventofcode/comments/1hla5ql/comment/m3kws15/.
         // I find it very unsatisfactory but it works :/
@@ -281,7 +380,7 @@ impl Puzzle for Day24 {
                 Wire::Named(_) => None,
             })
             .max()
-            .unwrap();
+            .context("There are no input wires")?;
 
         let odd_gates = extract_odd_gates(input_bit_count, &self.connections);
         let mut odd_gates = odd_gates
@@ -289,6 +388,6 @@ impl Puzzle for Day24 {
             .map(|wire| wire.show())
             .collect::<Vec<_>>();
         odd_gates.sort();
-        Some(odd_gates.join(","))
+        Ok(odd_gates.join(","))
     }
 }
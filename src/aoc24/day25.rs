@@ -1,4 +1,4 @@
-use crate::puzzle::Puzzle;
+use crate::puzzle::{Context as _, Puzzle, PuzzleError, PuzzleResult};
 
 const LOCK_SIZE: usize = 5;
 
@@ -39,20 +39,71 @@ fn fits(lock: Shape, key: Shape) -> bool {
         .all(|(x, y)| usize::from(x + y) <= LOCK_SIZE)
 }
 
+#[derive(Clone)]
 pub struct Day25 {
     locks: Vec<Shape>,
     keys: Vec<Shape>,
 }
 
+register_puzzle!(Day25, year = 2024, day = 25, title = "Code Chronicle", parts = [1]);
+
+examples![
+    schematics {
+        input: "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+",
+        part1: 3,
+    },
+];
+
 impl Puzzle for Day25 {
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> PuzzleResult<Self> {
         let mut locks = Vec::new();
         let mut keys = Vec::new();
 
         for shape in input.split("\n\n") {
-            let (kind, shape) = parse_shape(shape)?;
+            let (kind, shape) = parse_shape(shape).context_at(input, shape, "Empty schematic")?;
 
             match kind {
                 ShapeKind::Lock => locks.push(shape),
@@ -60,10 +111,10 @@ impl Puzzle for Day25 {
             }
         }
 
-        Some(Day25 { locks, keys })
+        Ok(Day25 { locks, keys })
     }
 
-    fn part1(self) -> Option<Self::Output> {
+    fn part1(self) -> PuzzleResult<Self::Output1> {
         let mut fitting = 0;
 
         for &lock in self.locks.iter() {
@@ -74,10 +125,10 @@ impl Puzzle for Day25 {
             }
         }
 
-        Some(fitting)
+        Ok(fitting)
     }
 
-    fn part2(self) -> Option<Self::Output> {
-        None
+    fn part2(self) -> PuzzleResult<Self::Output2> {
+        Err(PuzzleError::new("There is no part 2 on the last day"))
     }
 }
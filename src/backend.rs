@@ -0,0 +1,242 @@
+#[cfg(test)]
+use std::collections::HashMap;
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT},
+};
+
+use crate::description::main_content;
+
+// Where puzzle inputs come from and where answers go. The outcome of a
+// submission is the text of the page that would be shown on the AoC website,
+// which is what `Verdict::from_outcome` understands
+pub trait Backend {
+    fn get_input(&mut self, year: i32, day: u32) -> anyhow::Result<String>;
+
+    // The HTML page describing the puzzle
+    fn get_puzzle_page(&mut self, year: i32, day: u32) -> anyhow::Result<String>;
+
+    fn submit_answer(
+        &mut self,
+        year: i32,
+        day: u32,
+        part: i64,
+        answer: &str,
+    ) -> anyhow::Result<String>;
+}
+
+// Mimics the wording of the AoC website for backends that know the answers
+pub fn judge(expected: &str, answer: &str) -> String {
+    if expected == answer {
+        return String::from("That's the right answer!");
+    }
+
+    let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+
+    format!("That's not the right answer{hint}.")
+}
+
+// Talks to the AoC website, or to anything that answers the same requests
+// such as the stand-in server of `serve`
+pub struct AocBackend {
+    client: Client,
+    cookie_file: PathBuf,
+    base_url: String,
+}
+
+// The automation guidelines of AoC ask tools to say who they are
+const USER_AGENT_NAME: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+impl AocBackend {
+    pub fn new(cookie_file: &Path, base_url: &str) -> anyhow::Result<Self> {
+        let user_agent = HeaderValue::from_static(USER_AGENT_NAME);
+        let headers = HeaderMap::from_iter([(USER_AGENT, user_agent)]);
+
+        Ok(AocBackend {
+            client: Client::builder().default_headers(headers).build()?,
+            cookie_file: PathBuf::from(cookie_file),
+            base_url: String::from(base_url.trim_end_matches('/')),
+        })
+    }
+
+    fn session_cookie(&self) -> anyhow::Result<String> {
+        let cookie = read_to_string(&self.cookie_file).map_err(|error| {
+            anyhow!("Cannot read the session cookie from {}: {error}", self.cookie_file.display())
+        })?;
+
+        Ok(format!("session={}", cookie.trim()))
+    }
+
+    fn send(&self, request: RequestBuilder) -> anyhow::Result<String> {
+        let response = request.send()?;
+        let status = response.status();
+        let body = response.text()?;
+
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(anyhow!("The server answered {status}: {}", body.trim()))
+        }
+    }
+}
+
+impl Backend for AocBackend {
+    fn get_input(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        self.send(
+            self.client
+                .get(format!("{}/{year}/day/{day}/input", self.base_url))
+                .header(COOKIE, self.session_cookie()?),
+        )
+    }
+
+    fn get_puzzle_page(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        let mut request = self.client.get(format!("{}/{year}/day/{day}", self.base_url));
+
+        // Anyone can read part 1, but part 2 only shows up for those who
+        // solved part 1
+        if let Ok(cookie) = self.session_cookie() {
+            request = request.header(COOKIE, cookie);
+        }
+
+        self.send(request)
+    }
+
+    fn submit_answer(
+        &mut self,
+        year: i32,
+        day: u32,
+        part: i64,
+        answer: &str,
+    ) -> anyhow::Result<String> {
+        let response = self.send(
+            self.client
+                .post(format!("{}/{year}/day/{day}/answer", self.base_url))
+                .header(COOKIE, self.session_cookie()?)
+                .form(&[("level", part.to_string()), ("answer", answer.to_string())]),
+        )?;
+
+        let main = main_content(&response)
+            .ok_or_else(|| anyhow!("Unexpected response from the server"))?;
+
+        Ok(String::from(main))
+    }
+}
+
+// Serves the inputs stored as `<year>/<dd>.txt` and the puzzle pages stored as
+// `<year>/<dd>.html` in a directory, and judges the answers against
+// `<year>/<dd>.answers`, which holds the answer of part 1 on its first line
+// and the answer of part 2 on its second line
+pub struct DirectoryBackend {
+    directory: PathBuf,
+}
+
+impl DirectoryBackend {
+    pub fn new(directory: &Path) -> Self {
+        DirectoryBackend {
+            directory: PathBuf::from(directory),
+        }
+    }
+
+    fn path(&self, year: i32, day: u32, extension: &str) -> PathBuf {
+        self.directory
+            .join(format!("{year}"))
+            .join(format!("{day:02}.{extension}"))
+    }
+}
+
+impl Backend for DirectoryBackend {
+    fn get_input(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        let path = self.path(year, day, "txt");
+
+        read_to_string(&path).map_err(|error| anyhow!("Cannot read {}: {error}", path.display()))
+    }
+
+    fn get_puzzle_page(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        let path = self.path(year, day, "html");
+
+        read_to_string(&path).map_err(|error| anyhow!("Cannot read {}: {error}", path.display()))
+    }
+
+    fn submit_answer(
+        &mut self,
+        year: i32,
+        day: u32,
+        part: i64,
+        answer: &str,
+    ) -> anyhow::Result<String> {
+        if part != 1 && part != 2 {
+            return Err(anyhow!("There is no part {part}"));
+        }
+
+        let path = self.path(year, day, "answers");
+        let answers = read_to_string(&path)
+            .map_err(|error| anyhow!("Cannot read {}: {error}", path.display()))?;
+
+        let expected = answers
+            .lines()
+            .nth(part as usize - 1)
+            .ok_or_else(|| anyhow!("{} has no answer for part {part}", path.display()))?;
+
+        Ok(judge(expected.trim(), answer))
+    }
+}
+
+// Keeps everything in memory and remembers what was submitted
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeBackend {
+    pub inputs: HashMap<(i32, u32), String>,
+    pub pages: HashMap<(i32, u32), String>,
+    pub answers: HashMap<(i32, u32, i64), String>,
+    pub submissions: Vec<(i32, u32, i64, String)>,
+    // Given instead of judging the answers
+    pub outcome: Option<String>,
+}
+
+#[cfg(test)]
+impl Backend for FakeBackend {
+    fn get_input(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        self.inputs
+            .get(&(year, day))
+            .cloned()
+            .ok_or_else(|| anyhow!("No input for day {day} of year {year}"))
+    }
+
+    fn get_puzzle_page(&mut self, year: i32, day: u32) -> anyhow::Result<String> {
+        self.pages
+            .get(&(year, day))
+            .cloned()
+            .ok_or_else(|| anyhow!("No puzzle page for day {day} of year {year}"))
+    }
+
+    fn submit_answer(
+        &mut self,
+        year: i32,
+        day: u32,
+        part: i64,
+        answer: &str,
+    ) -> anyhow::Result<String> {
+        self.submissions.push((year, day, part, String::from(answer)));
+
+        if let Some(outcome) = &self.outcome {
+            return Ok(outcome.clone());
+        }
+
+        let expected = self
+            .answers
+            .get(&(year, day, part))
+            .ok_or_else(|| anyhow!("No answer for part {part} of day {day} of year {year}"))?;
+
+        Ok(judge(expected, answer))
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+
+// How long the server asks to wait before answering again, either because an
+// answer was given too recently ("You have 1m 7s left to wait") or because it
+// was wrong ("Please wait one minute before trying again")
+pub fn wait_time(outcome: &str) -> Option<Duration> {
+    let left_to_wait =
+        Regex::new(r"You have (?:(?<minutes>\d+)m )?(?<seconds>\d+)s left to wait").unwrap();
+    let wait_before_trying_again = Regex::new(
+        r"(?i)wait (?<amount>one|\d+) (?<unit>minute|second)s? before trying again",
+    )
+    .unwrap();
+
+    if let Some(captures) = left_to_wait.captures(outcome) {
+        let minutes = captures
+            .name("minutes")
+            .map_or(Ok(0), |minutes| minutes.as_str().parse::<u64>())
+            .ok()?;
+        let seconds = captures["seconds"].parse::<u64>().ok()?;
+
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let captures = wait_before_trying_again.captures(outcome)?;
+    let amount = match &captures["amount"] {
+        "one" => 1,
+        amount => amount.parse::<u64>().ok()?,
+    };
+    let unit = match &captures["unit"].to_lowercase()[..] {
+        "minute" => 60,
+        _ => 1,
+    };
+
+    Some(Duration::from_secs(amount * unit))
+}
+
+// Remembers until when the server refuses answers, as seconds since the Unix
+// epoch, so that the next runs do not get penalized for answering too early
+pub struct Cooldown {
+    path: PathBuf,
+}
+
+impl Cooldown {
+    pub fn new(path: &Path) -> Self {
+        Cooldown {
+            path: PathBuf::from(path),
+        }
+    }
+
+    fn now() -> Duration {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+    }
+
+    pub fn remaining(&self) -> anyhow::Result<Option<Duration>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let until = Duration::from_secs(read_to_string(&self.path)?.trim().parse::<u64>()?);
+
+        Ok(until.checked_sub(Self::now()).filter(|remaining| !remaining.is_zero()))
+    }
+
+    pub fn start(&self, duration: Duration) -> anyhow::Result<()> {
+        if let Some(directory) = self.path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        // Rounded up, waiting one second too long is better than getting
+        // penalized
+        let until = Self::now() + duration + Duration::from_millis(999);
+
+        Ok(write(&self.path, format!("{}\n", until.as_secs()))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_wait_time_of_the_server() {
+        let outcomes = [
+            ("You have 47s left to wait.", Some(47)),
+            ("You have 1m 7s left to wait.", Some(67)),
+            ("Please wait one minute before trying again.", Some(60)),
+            ("please wait 5 minutes before trying again.", Some(300)),
+            ("That's the right answer!", None),
+        ];
+
+        for (outcome, seconds) in outcomes {
+            assert_eq!(wait_time(outcome), seconds.map(Duration::from_secs), "{outcome}");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+// What matters on an AoC page, without the header, the sidebar and such
+pub fn main_content(page: &str) -> Option<&str> {
+    Regex::new(r"(?s)<main>(?<main>.*)</main>")
+        .unwrap()
+        .captures(page)
+        .and_then(|captures| captures.name("main"))
+        .map(|main| main.as_str())
+}
+
+pub fn to_markdown(page: &str) -> String {
+    html2md::parse_html(main_content(page).unwrap_or(page))
+}
+
+fn decode_entities(text: &str) -> String {
+    Regex::new(r"&(?<entity>#?\w+);")
+        .unwrap()
+        .replace_all(text, |captures: &regex::Captures| {
+            let entity = &captures["entity"];
+
+            match entity {
+                "lt" => String::from("<"),
+                "gt" => String::from(">"),
+                "amp" => String::from("&"),
+                "quot" => String::from("\""),
+                "apos" => String::from("'"),
+                _ => entity
+                    .strip_prefix('#')
+                    .and_then(|code| code.parse::<u32>().ok())
+                    .and_then(char::from_u32)
+                    .map_or_else(|| String::from(&captures[0]), String::from),
+            }
+        })
+        .into_owned()
+}
+
+// The title of the puzzle, as in "--- Day 1: Historian Hysteria ---"
+pub fn title(page: &str) -> Option<String> {
+    Regex::new(r"<h2>--- Day \d+: (?<title>.*?) ---</h2>")
+        .unwrap()
+        .captures(page)
+        .map(|captures| decode_entities(&captures["title"]))
+}
+
+// The `<pre><code>` blocks of a puzzle page, which hold its examples along
+// with some intermediate states and outputs. The emphasis inside them is
+// dropped
+pub fn extract_examples(page: &str) -> Vec<String> {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+
+    Regex::new(r"(?s)<pre><code>(?<block>.*?)</code></pre>")
+        .unwrap()
+        .captures_iter(page)
+        .map(|captures| decode_entities(&tag.replace_all(&captures["block"], "")))
+        .collect()
+}
+
+pub fn description_path(description_dir: &Path, year: i32, day: u32) -> PathBuf {
+    description_dir
+        .join(format!("{year}"))
+        .join(format!("{day:02}.md"))
+}
+
+// Numbered from 1, like the `--example` flag
+pub fn example_path(description_dir: &Path, year: i32, day: u32, number: usize) -> PathBuf {
+    description_dir
+        .join(format!("{year}"))
+        .join(format!("{day:02}-example-{number}.txt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_examples_without_markup() {
+        let page = "<main><p>For example:</p>\n\
+                    <pre><code>#.<em>&lt;</em>.\n..&gt;&amp;\n</code></pre>\n\
+                    <p>Then:</p><pre><code>x &#64; y\n</code></pre></main>";
+
+        assert_eq!(extract_examples(page), ["#.<.\n..>&\n", "x @ y\n"]);
+    }
+
+    #[test]
+    fn reads_the_title() {
+        let page = "<main><article><h2>--- Day 3: Mull It Over &amp; Over ---</h2></article></main>";
+
+        assert_eq!(title(page).as_deref(), Some("Mull It Over & Over"));
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    fmt::Display,
+    fs::{read_to_string, OpenOptions},
+    io::Write as _,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    Wait,
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn from_outcome(outcome: &str) -> Option<Verdict> {
+        if outcome.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if outcome.contains("That's not the right answer") {
+            if outcome.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if outcome.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Incorrect)
+            }
+        } else if outcome.contains("You gave an answer too recently") {
+            Some(Verdict::Wait)
+        } else if outcome.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        match self {
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow => true,
+            Verdict::Correct | Verdict::Wait | Verdict::WrongLevel => false,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wait => "wait",
+            Verdict::WrongLevel => "wrong-level",
+        };
+
+        f.pad(verdict)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wait" => Ok(Verdict::Wait),
+            "wrong-level" => Ok(Verdict::WrongLevel),
+            unknown => Err(anyhow!("Unknown verdict {unknown}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Submission {
+    pub year: i32,
+    pub day: u32,
+    pub part: i64,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.year, self.day, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.splitn(5, '\t');
+        let mut next_field = || fields.next().ok_or_else(|| anyhow!("Truncated line {line}"));
+
+        Ok(Submission {
+            year: next_field()?.parse()?,
+            day: next_field()?.parse()?,
+            part: next_field()?.parse()?,
+            verdict: next_field()?.parse()?,
+            answer: String::from(next_field()?),
+        })
+    }
+}
+
+pub enum Check {
+    Submit,
+    AlreadyAccepted,
+    Refuse(String),
+}
+
+// The ledger of all the answers submitted so far, stored as one tab-separated
+// line per submission so that it can be appended to and read by hand
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> anyhow::Result<History> {
+        let submissions = if path.exists() {
+            read_to_string(path)?
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.parse::<Submission>())
+                .collect::<anyhow::Result<Vec<_>>>()?
+        } else {
+            Vec::new()
+        };
+
+        Ok(History {
+            path: PathBuf::from(path),
+            submissions,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    pub fn for_part(&self, year: i32, day: u32, part: i64) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |submission| {
+            submission.year == year && submission.day == day && submission.part == part
+        })
+    }
+
+    pub fn accepted_answer(&self, year: i32, day: u32, part: i64) -> Option<&str> {
+        self.for_part(year, day, part)
+            .find(|submission| submission.verdict == Verdict::Correct)
+            .map(|submission| submission.answer.as_str())
+    }
+
+    // The answer must be strictly above every answer that was too low and
+    // strictly below every answer that was too high
+    fn bounds(&self, year: i32, day: u32, part: i64) -> (Option<i64>, Option<i64>) {
+        let mut lower_bound = None;
+        let mut upper_bound = None;
+
+        for submission in self.for_part(year, day, part) {
+            match (submission.verdict, submission.answer.parse::<i64>()) {
+                (Verdict::TooLow, Ok(value)) => {
+                    lower_bound = Some(lower_bound.map_or(value, |bound: i64| bound.max(value)));
+                }
+                (Verdict::TooHigh, Ok(value)) => {
+                    upper_bound = Some(upper_bound.map_or(value, |bound: i64| bound.min(value)));
+                }
+                _ => (),
+            }
+        }
+
+        (lower_bound, upper_bound)
+    }
+
+    pub fn check(&self, year: i32, day: u32, part: i64, answer: &str) -> Check {
+        if let Some(accepted) = self.accepted_answer(year, day, part) {
+            return if accepted == answer {
+                Check::AlreadyAccepted
+            } else {
+                Check::Refuse(format!(
+                    "part {part} was already solved with answer {accepted}"
+                ))
+            };
+        }
+
+        if let Some(known_wrong) = self
+            .for_part(year, day, part)
+            .find(|submission| submission.verdict.is_wrong() && submission.answer == answer)
+        {
+            return Check::Refuse(format!(
+                "{answer} was already submitted and judged {}",
+                known_wrong.verdict
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            match self.bounds(year, day, part) {
+                (Some(lower_bound), _) if value <= lower_bound => {
+                    return Check::Refuse(format!(
+                        "{answer} is not above {lower_bound}, which was too low"
+                    ));
+                }
+                (_, Some(upper_bound)) if value >= upper_bound => {
+                    return Check::Refuse(format!(
+                        "{answer} is not below {upper_bound}, which was too high"
+                    ));
+                }
+                _ => (),
+            }
+        }
+
+        Check::Submit
+    }
+
+    pub fn record(&mut self, submission: Submission) -> anyhow::Result<()> {
+        if let Some(directory) = self.path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{submission}")?;
+
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(lines: &[&str]) -> History {
+        History {
+            path: PathBuf::from("history.tsv"),
+            submissions: lines.iter().map(|line| line.parse().unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_the_bounds() {
+        let history = history(&[
+            "2024\t1\t1\ttoo-low\t100",
+            "2024\t1\t1\ttoo-low\t120",
+            "2024\t1\t1\ttoo-high\t200",
+            "2024\t1\t1\tincorrect\t150",
+        ]);
+        let check = |answer| history.check(2024, 1, 1, answer);
+
+        assert!(matches!(check("120"), Check::Refuse(_)));
+        assert!(matches!(check("110"), Check::Refuse(_)));
+        assert!(matches!(check("200"), Check::Refuse(_)));
+        assert!(matches!(check("250"), Check::Refuse(_)));
+        assert!(matches!(check("150"), Check::Refuse(_)));
+        assert!(matches!(check("121"), Check::Submit));
+        assert!(matches!(check("199"), Check::Submit));
+
+        // The bounds only apply to numbers, and only to the same part
+        assert!(matches!(check("abc"), Check::Submit));
+        assert!(matches!(history.check(2024, 1, 2, "110"), Check::Submit));
+    }
+
+    #[test]
+    fn recognizes_an_accepted_answer() {
+        let history = history(&["2024\t1\t1\ttoo-low\t10", "2024\t1\t1\tcorrect\t42"]);
+
+        assert!(matches!(history.check(2024, 1, 1, "42"), Check::AlreadyAccepted));
+        assert!(matches!(history.check(2024, 1, 1, "43"), Check::Refuse(_)));
+        assert_eq!(history.accepted_answer(2024, 1, 1), Some("42"));
+    }
+
+    #[test]
+    fn reads_back_written_submissions() {
+        let submission = Submission {
+            year: 2024,
+            day: 25,
+            part: 1,
+            answer: String::from("a\tb"),
+            verdict: Verdict::WrongLevel,
+        };
+        let line = submission.to_string();
+        let read = line.parse::<Submission>().unwrap();
+
+        assert_eq!(line, "2024\t25\t1\twrong-level\ta\tb");
+        assert_eq!(
+            (read.year, read.day, read.part, read.answer, read.verdict),
+            (2024, 25, 1, String::from("a\tb"), Verdict::WrongLevel)
+        );
+        assert!("2024\t25\t1".parse::<Submission>().is_err());
+        assert!("2024\t25\t1\tmaybe\t7".parse::<Submission>().is_err());
+    }
+}
@@ -0,0 +1,33 @@
+// Inputs saved from a browser or an editor can differ from downloaded ones in
+// ways that are invisible on screen: Windows line endings, spaces at the end
+// of lines, blank lines or a missing line break at the end. Once normalised,
+// every line ends with "\n" and has no trailing whitespace, and the input
+// ends with its last non-blank line
+pub fn normalize(input: &str) -> String {
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+
+    lines[..end].iter().map(|line| format!("{line}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings_and_trailing_whitespace() {
+        let inputs = [
+            ("1 2\n3 4\n", "1 2\n3 4\n"),
+            ("1 2\r\n \r\n3 4", "1 2\n\n3 4\n"),
+            ("  1 2  \t\n\n3 4\n\n\n", "  1 2\n\n3 4\n"),
+            ("\n \n", ""),
+        ];
+
+        for (input, normalized) in inputs {
+            assert_eq!(normalize(input), normalized, "{input:?}");
+        }
+    }
+}
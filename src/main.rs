@@ -1,190 +1,1458 @@
+pub mod backend;
+pub mod cooldown;
+pub mod description;
+pub mod history;
+pub mod input;
 pub mod position;
+pub mod profile;
 pub mod puzzle;
+#[macro_use]
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+pub mod server;
+pub mod timing;
+pub mod unlock;
+pub mod watch;
 
-mod aoc22 {
-    pub mod day01;
-}
-
-mod aoc24 {
-    pub mod day01;
-    pub mod day02;
-    pub mod day03;
-    pub mod day04;
-    pub mod day05;
-    pub mod day06;
-    pub mod day07;
-    pub mod day08;
-    pub mod day09;
-    pub mod day10;
-    pub mod day11;
-    pub mod day12;
-    pub mod day13;
-    pub mod day14;
-    pub mod day15;
-    pub mod day16;
-    pub mod day17;
-    pub mod day19;
-    pub mod day18;
-    pub mod day20;
-    pub mod day21;
-    pub mod day22;
-    pub mod day23;
-    pub mod day24;
-    pub mod day25;
-}
-
-use std::{fs::read_to_string, path::PathBuf};
+// Declares the `aocYY::dayNN` modules and the `PUZZLES` table holding their
+// registrations, see build.rs
+include!(concat!(env!("OUT_DIR"), "/puzzles.rs"));
+
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::Duration,
+};
 
 use anyhow::anyhow;
-use aoc_client::AocClient;
+use backend::{AocBackend, Backend, DirectoryBackend};
 
 use clap::Parser as _;
-use puzzle::Puzzle;
+use cooldown::{wait_time, Cooldown};
+use description::{description_path, example_path, extract_examples, title, to_markdown};
+use history::{Check, History, Submission, Verdict};
+use input::normalize;
+use profile::Profile;
+use puzzle::{PuzzleError, PuzzleResult};
+use registry::{Example, Registration, Solution};
+use report::{Format, Record};
+use scaffold::{day_module, module_path};
+use server::StandInServer;
+use timing::Timings;
+use unlock::wait_for_unlock;
+use watch::{compare, FileWatcher};
 
 #[derive(clap::Parser)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
-    #[arg(short, long)]
-    year: i32,
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    #[arg(short, long)]
-    day: u32,
+    #[arg(short, long, required = true)]
+    year: Option<i32>,
 
-    #[arg(short, long)]
-    part: i64,
+    #[arg(short, long, required = true)]
+    day: Option<u32>,
+
+    #[arg(short, long, required_unless_present_any = ["watch", "wait"])]
+    part: Option<Part>,
 
     #[arg(short, long, default_value = "false")]
     no_submit: bool,
 
-    #[arg(short, long, default_value = ".cookie")]
-    cookie_file: PathBuf,
+    /// When the server refuses answers for a while, wait and submit anyway
+    /// instead of giving up
+    #[arg(long, default_value = "false")]
+    wait_cooldown: bool,
+
+    /// Solve the puzzle this many times and report the min, median and max
+    /// duration of each phase
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// Use the cookie, input cache and history of this profile, which are
+    /// kept in `<profiles dir>/<name>`. The flags setting these files one by
+    /// one take precedence
+    #[arg(long, env = "AOC_PROFILE", global = true)]
+    profile: Option<String>,
+
+    #[arg(long, default_value = "profiles", global = true)]
+    profiles_dir: PathBuf,
+
+    /// Defaults to `.cookie`, or to the cookie of the profile
+    #[arg(short, long, global = true)]
+    cookie_file: Option<PathBuf>,
+
+    /// Address of the AoC website, which can be a stand-in server started
+    /// with the `serve` command
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com", global = true)]
+    base_url: String,
+
+    /// Read the inputs from `<year>/<dd>.txt` in this directory and check the
+    /// answers against `<year>/<dd>.answers` instead of using the AoC website
+    #[arg(long, global = true)]
+    local_dir: Option<PathBuf>,
 
     #[arg(short, long)]
     input_file: Option<PathBuf>,
+
+    /// Solve the N-th example of the puzzle description instead of the real
+    /// input. Nothing is submitted in this mode
+    #[arg(long, conflicts_with = "input_file", value_parser = clap::value_parser!(u32).range(1..))]
+    example: Option<u32>,
+
+    /// Directory where downloaded puzzle inputs are cached, as
+    /// `<year>/<dd>.txt`. Defaults to `inputs`, or to the inputs of the
+    /// profile
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// Download the input again even if it is already cached
+    #[arg(long, default_value = "false")]
+    refresh: bool,
+
+    /// Give the input to the puzzle exactly as it was read, instead of
+    /// normalising its line endings and trailing whitespace
+    #[arg(long, default_value = "false", global = true)]
+    raw_input: bool,
+
+    /// If the puzzle is not unlocked yet, wait for it before downloading the
+    /// input. Stops once the input is cached, unless `--part` is given and
+    /// the day is implemented
+    #[arg(long, default_value = "false", conflicts_with_all = ["input_file", "example"])]
+    wait: bool,
+
+    /// Submit the answers computed after `--wait`, which are only shown
+    /// otherwise
+    #[arg(
+        long,
+        default_value = "false",
+        requires_all = ["wait", "part"],
+        conflicts_with = "no_submit"
+    )]
+    submit: bool,
+
+    /// Solve both parts again whenever the input file changes, e.g. an
+    /// example saved by `describe` given with `--input-file`, and show how
+    /// the answers changed. Nothing is submitted in this mode
+    #[arg(long, default_value = "false", conflicts_with_all = ["part", "example", "wait"])]
+    watch: bool,
+
+    /// Directory where puzzle descriptions are cached, as `<year>/<dd>.md`,
+    /// along with their examples as `<year>/<dd>-example-<n>.txt`
+    #[arg(long, default_value = "descriptions", global = true)]
+    description_dir: PathBuf,
+
+    /// Ledger of every submitted answer and the verdict it received.
+    /// Defaults to `history.tsv`, or to the history of the profile
+    #[arg(long, global = true)]
+    history_file: Option<PathBuf>,
+
+    /// How the results are printed. With `json`, the messages meant for
+    /// humans go to the standard error
+    #[arg(long, value_enum, default_value = "text", global = true)]
+    format: Format,
+}
+
+impl Args {
+    fn profile(&self) -> Profile {
+        let profile = match &self.profile {
+            Some(name) => Profile::named(&self.profiles_dir, name),
+            None => Profile::default_profile(),
+        };
+
+        Profile {
+            cookie_file: self.cookie_file.clone().unwrap_or(profile.cookie_file),
+            input_dir: self.input_dir.clone().unwrap_or(profile.input_dir),
+            history_file: self.history_file.clone().unwrap_or(profile.history_file),
+            ..profile
+        }
+    }
+
+    // What the puzzle gets to parse, see `normalize`
+    fn prepare_input(&self, input: String) -> String {
+        if self.raw_input {
+            input
+        } else {
+            normalize(&input)
+        }
+    }
+
+    // Messages meant for humans, which must not get mixed with JSON records
+    fn inform(&self, message: impl std::fmt::Display) {
+        match self.format {
+            Format::Text => println!("{message}"),
+            Format::Json => eprintln!("{message}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+
+    #[value(name = "2")]
+    Two,
+
+    /// Parse the input once and solve both parts
+    Both,
+}
+
+impl Part {
+    fn numbers(self) -> &'static [i64] {
+        match self {
+            Part::One => &[1],
+            Part::Two => &[2],
+            Part::Both => &[1, 2],
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Run both parts of every registered day against the cached inputs.
+    /// Nothing is ever submitted in this mode
+    All {
+        /// Only run the days of this year
+        #[arg(short, long)]
+        year: Option<i32>,
+    },
+
+    /// Run both parts of every registered day against the cached inputs and
+    /// compare the answers with the accepted ones from the history. Fails if
+    /// any of them does not match
+    Verify {
+        /// Only verify the days of this year
+        #[arg(short, long)]
+        year: Option<i32>,
+    },
+
+    /// Show the registered days, which of their parts are solved and whether
+    /// their input is cached
+    List {
+        /// Only list the days of this year
+        #[arg(short, long)]
+        year: Option<i32>,
+    },
+
+    /// Show the answers submitted so far and their verdicts
+    History {
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        #[arg(short, long)]
+        day: Option<u32>,
+    },
+
+    /// Download the description of a puzzle, cache it as Markdown and extract
+    /// its example blocks as candidate examples
+    Describe {
+        #[arg(short, long)]
+        year: i32,
+
+        #[arg(short, long)]
+        day: u32,
+
+        /// Download the description again even if it is already cached, e.g.
+        /// to get part 2 once part 1 is solved
+        #[arg(long, default_value = "false")]
+        refresh: bool,
+    },
+
+    /// Solve a day with the input of every profile and compare the answers
+    /// with the ones accepted for each profile, to check that the solution
+    /// does not only work for one input. Nothing is submitted in this mode
+    Profiles {
+        #[arg(short, long)]
+        year: i32,
+
+        #[arg(short, long)]
+        day: u32,
+    },
+
+    /// Start a new day: generate its module from a template, with the title
+    /// and the first example block of the puzzle page when it is available,
+    /// and download its input when a cookie or a local directory is set up
+    New {
+        #[arg(short, long)]
+        year: i32,
+
+        #[arg(short, long)]
+        day: u32,
+
+        /// Source directory of the crate, the module is created as
+        /// `aocYY/dayNN.rs` in it
+        #[arg(long, default_value = "src")]
+        src_dir: PathBuf,
+    },
+
+    /// Stand in for the AoC website, serving inputs, puzzle pages and
+    /// submission verdicts from fixture files, see server.rs for their layout
+    Serve {
+        #[arg(long, default_value = "fixtures")]
+        fixtures: PathBuf,
+
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+
+        /// Seconds to wait before answering again after a wrong answer
+        #[arg(long, default_value = "60")]
+        cooldown: u64,
+    },
 }
 
 struct Client<'a> {
-    aoc_client: Option<AocClient>,
+    backend: &'a mut dyn Backend,
     args: &'a Args,
+    profile: &'a Profile,
+    year: i32,
+    day: u32,
 }
 
-impl Client<'_> {
-    fn new(args: &Args) -> Client {
+impl<'a> Client<'a> {
+    fn new(
+        backend: &'a mut dyn Backend,
+        args: &'a Args,
+        profile: &'a Profile,
+        year: i32,
+        day: u32,
+    ) -> Client<'a> {
         Client {
-            aoc_client: None,
+            backend,
             args,
+            profile,
+            year,
+            day,
         }
     }
 
-    fn with_aoc_client<T>(
-        &mut self,
-        run: impl FnOnce(&AocClient) -> anyhow::Result<T>,
-    ) -> anyhow::Result<T> {
-        match &self.aoc_client {
-            None => {
-                let client = AocClient::builder()
-                    .session_cookie_from_file(&self.args.cookie_file)?
-                    .year(self.args.year)?
-                    .day(self.args.day)?
-                    .build()?;
+    // An answer which was already accepted is not submitted again but still
+    // counts as correct
+    fn submit_answer(&mut self, puzzle_part: i64, answer: &str) -> anyhow::Result<Verdict> {
+        let mut history = History::load(&self.profile.history_file)?;
+
+        match history.check(self.year, self.day, puzzle_part, answer) {
+            Check::Submit => (),
+            Check::AlreadyAccepted => {
+                self.args
+                    .inform(format!("The answer to part {puzzle_part} was already accepted"));
+                return Ok(Verdict::Correct);
+            }
+            Check::Refuse(reason) => return Err(anyhow!("Not submitting: {reason}")),
+        }
+
+        // Answering while the server still refuses answers only makes the
+        // wait longer
+        let cooldown = Cooldown::new(&self.profile.cooldown_file);
+
+        loop {
+            if let Some(remaining) = cooldown.remaining()? {
+                if !self.args.wait_cooldown {
+                    return Err(anyhow!(
+                        "Not submitting: the server refuses answers for another {remaining:.0?}"
+                    ));
+                }
+
+                self.args.inform(format!(
+                    "Waiting {remaining:.0?} for the server to accept answers again"
+                ));
+                sleep(remaining);
+            }
 
-                let result = run(&client);
+            let outcome = self
+                .backend
+                .submit_answer(self.year, self.day, puzzle_part, answer)?;
+            self.args
+                .inform(format!("\n{}", html2text::from_read(outcome.as_bytes(), 80)));
 
-                self.aoc_client = Some(client);
+            let verdict = Verdict::from_outcome(&outcome)
+                .ok_or_else(|| anyhow!("Could not understand the submission outcome"))?;
 
-                result
+            history.record(Submission {
+                year: self.year,
+                day: self.day,
+                part: puzzle_part,
+                answer: String::from(answer),
+                verdict,
+            })?;
+
+            // Submitting again without knowing how long to wait would only be
+            // penalized again
+            match wait_time(&outcome) {
+                Some(wait) => cooldown.start(wait)?,
+                None if verdict == Verdict::Wait => {
+                    return Err(anyhow!("The server refuses answers for an unknown time"));
+                }
+                None => (),
+            }
+
+            if verdict != Verdict::Wait || !self.args.wait_cooldown {
+                return Ok(verdict);
             }
-            Some(client) => run(client),
         }
     }
 
-    fn submit_answer(&mut self, puzzle_part: i64, answer: impl std::fmt::Display) -> anyhow::Result<()> {
-        println!("Answer is {}", answer);
+    // The input may not be served right at the unlock time, so it is asked
+    // again a few times
+    fn get_input_once_unlocked(&mut self) -> anyhow::Result<String> {
+        let mut attempts = 1..10;
 
-        if !self.args.no_submit {
-            self.with_aoc_client(|client| {
-                Ok(client.submit_answer_and_show_outcome(puzzle_part, answer)?)
-            })?
+        loop {
+            match self.get_input() {
+                Ok(input) => return Ok(input),
+                Err(error) if attempts.next().is_some() => {
+                    eprintln!("Not able to get the input yet: {error}");
+                    sleep(Duration::from_secs(1));
+                }
+                Err(error) => return Err(error),
+            }
         }
-
-        Ok(())
     }
 
     fn get_input(&mut self) -> anyhow::Result<String> {
         match &self.args.input_file {
-            None => self.with_aoc_client(|client| Ok(client.get_input()?)),
+            None => {
+                let cached_input = input_path(&self.profile.input_dir, self.year, self.day);
+
+                if !self.args.refresh && cached_input.exists() {
+                    return Ok(read_to_string(cached_input)?);
+                }
+
+                let input = self.backend.get_input(self.year, self.day)?;
+
+                if let Some(directory) = cached_input.parent() {
+                    create_dir_all(directory)?;
+                }
+                write(cached_input, &input)?;
+
+                Ok(input)
+            }
             Some(path) => Ok(read_to_string(path)?),
         }
     }
 }
 
-macro_rules! make_puzzle_runner {
-    [ $( ($year:literal, $day:literal, $day_type:ty) ),* ] => {
-        #[allow(clippy::zero_prefixed_literal)]
-        fn puzzle_runner(year: i32, day: u32, part: i64, input: &str) -> Option<Box<dyn std::fmt::Display>> {
-        $(
-            if year == $year && day == $day {
-                match part {
-                    1 => return Some(Box::new(<$day_type>::parse(input)?.part1()?)),
-                    2 => return Some(Box::new(<$day_type>::parse(input)?.part2()?)),
-                    _ => return None
+fn find_registration(year: i32, day: u32) -> PuzzleResult<&'static Registration> {
+    PUZZLES
+        .iter()
+        .find(|registration| registration.year == year && registration.day == day)
+        .ok_or_else(|| PuzzleError::new("This day is not implemented"))
+}
+
+fn find_example(year: i32, day: u32, number: u32) -> anyhow::Result<&'static Example> {
+    let examples = find_registration(year, day)?.examples;
+
+    examples.get(number as usize - 1).ok_or_else(|| {
+        anyhow!(
+            "Day {day} of year {year} has {} examples, there is no example {number}",
+            examples.len()
+        )
+    })
+}
+
+fn puzzle_runner(
+    year: i32,
+    day: u32,
+    parts: &[i64],
+    input: &str,
+) -> PuzzleResult<Solution> {
+    (find_registration(year, day)?.solver)(parts, input)
+}
+
+fn input_path(input_dir: &Path, year: i32, day: u32) -> PathBuf {
+    input_dir.join(format!("{year}")).join(format!("{day:02}.txt"))
+}
+
+#[derive(Clone)]
+enum Outcome {
+    Answer(String),
+    Failed(String),
+    MissingInput,
+    // The day has no such part, like the second part of the last day
+    NotApplicable,
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Failed(_) => write!(f, "failed"),
+            Outcome::MissingInput => write!(f, "missing input"),
+            Outcome::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+// How both parts of a day went, along with the time each of them took. The
+// input is parsed once for both parts
+struct DayRun {
+    parse_time: Option<Duration>,
+    parts: [(Outcome, Option<Duration>); 2],
+}
+
+impl DayRun {
+    // The same outcome for each of the `parts` the day has
+    fn without_answers(parts: &[i64], outcome: Outcome) -> DayRun {
+        DayRun {
+            parse_time: None,
+            parts: [1, 2].map(|part| {
+                if parts.contains(&part) {
+                    (outcome.clone(), None)
+                } else {
+                    (Outcome::NotApplicable, None)
+                }
+            }),
+        }
+    }
+
+    fn outcomes(self) -> [Outcome; 2] {
+        self.parts.map(|(outcome, _)| outcome)
+    }
+
+    // The parts the day does not have are left out
+    fn records(&self, year: i32, day: u32) -> Vec<Record> {
+        (1..)
+            .zip(self.parts.iter())
+            .filter(|(_, (outcome, _))| !matches!(outcome, Outcome::NotApplicable))
+            .map(|(part, _)| self.record(year, day, part))
+            .collect()
+    }
+
+    fn record(&self, year: i32, day: u32, part: i64) -> Record {
+        let (outcome, solve_time) = &self.parts[part as usize - 1];
+        let record = Record {
+            parse_time: self.parse_time.map(Timings::single).and_then(|time| time.summary()),
+            solve_time: solve_time.map(Timings::single).and_then(|time| time.summary()),
+            ..Record::new(year, day, part)
+        };
+
+        match outcome {
+            Outcome::Answer(answer) => Record {
+                answer: Some(answer.clone()),
+                ..record
+            },
+            Outcome::Failed(error) => Record {
+                error: Some(error.clone()),
+                ..record
+            },
+            Outcome::MissingInput => Record {
+                error: Some(format!("{outcome}")),
+                ..record
+            },
+            Outcome::NotApplicable => record,
+        }
+    }
+}
+
+fn run_both_parts(registration: &Registration, input: &str) -> DayRun {
+    let Registration {
+        year,
+        day,
+        solver,
+        parts,
+        ..
+    } = *registration;
+    // A panicking day should not prevent the other days from running, the
+    // panic message is still shown by the default hook
+    match std::panic::catch_unwind(|| solver(parts, input)) {
+        Ok(Ok(solution)) => {
+            let mut run = DayRun {
+                parse_time: Some(solution.parse_time),
+                ..DayRun::without_answers(&[], Outcome::NotApplicable)
+            };
+
+            for ((answer, time), &part) in solution.answers.into_iter().zip(parts) {
+                let outcome = match answer {
+                    Ok(answer) => Outcome::Answer(answer),
+                    Err(error) => {
+                        eprintln!("Part {part} of day {day} of year {year}: {}", error.show(input));
+                        Outcome::Failed(format!("{error}"))
+                    }
+                };
+
+                run.parts[part as usize - 1] = (outcome, Some(time));
+            }
+
+            run
+        }
+        Ok(Err(error)) => {
+            eprintln!("Day {day} of year {year}: {}", error.show(input));
+            DayRun::without_answers(parts, Outcome::Failed(format!("{error}")))
+        }
+        Err(_) => DayRun::without_answers(parts, Outcome::Failed(String::from("panicked"))),
+    }
+}
+
+// Solves both parts of every registered day, or only those of `year`, with
+// their cached input
+fn run_registered(args: &Args, input_dir: &Path, year: Option<i32>) -> Vec<(i32, u32, DayRun)> {
+    let mut rows = Vec::new();
+
+    for registration in PUZZLES {
+        if year.is_some_and(|year| year != registration.year) {
+            continue;
+        }
+
+        let input_file = input_path(input_dir, registration.year, registration.day);
+
+        let run = match read_to_string(input_file) {
+            Ok(input) => run_both_parts(registration, &args.prepare_input(input)),
+            Err(_) => DayRun::without_answers(registration.parts, Outcome::MissingInput),
+        };
+
+        rows.push((registration.year, registration.day, run));
+    }
+
+    rows
+}
+
+fn print_table<T: std::fmt::Display>(rows: &[(i32, u32, [T; 2])]) {
+    let width = rows
+        .iter()
+        .flat_map(|(_, _, cells)| cells.iter().map(|cell| format!("{cell}").len()))
+        .chain(std::iter::once("Part 1".len()))
+        .max()
+        .unwrap_or(0);
+
+    println!("Year  Day  {:<width$}  Part 2", "Part 1");
+
+    for (year, day, [part1, part2]) in rows.iter() {
+        println!("{year}   {day:02}  {:<width$}  {part2}", format!("{part1}"));
+    }
+}
+
+fn run_all(args: &Args, input_dir: &Path, year: Option<i32>) {
+    let runs = run_registered(args, input_dir, year);
+
+    if args.format == Format::Json {
+        for (year, day, run) in runs.iter() {
+            for record in run.records(*year, *day) {
+                record.print();
+            }
+        }
+
+        return;
+    }
+
+    let rows = runs
+        .into_iter()
+        .map(|(year, day, run)| (year, day, run.outcomes()))
+        .collect::<Vec<_>>();
+
+    print_table(&rows);
+
+    let count = |predicate: fn(&Outcome) -> bool| {
+        rows.iter()
+            .flat_map(|(_, _, outcomes)| outcomes.iter())
+            .filter(|outcome| predicate(outcome))
+            .count()
+    };
+
+    println!(
+        "\n{} answers, {} failures, {} missing inputs",
+        count(|outcome| matches!(outcome, Outcome::Answer(_))),
+        count(|outcome| matches!(outcome, Outcome::Failed(_))),
+        count(|outcome| matches!(outcome, Outcome::MissingInput)),
+    );
+}
+
+enum Verification {
+    Pass,
+    Fail,
+    NoInput,
+    NoAcceptedAnswer,
+    NotApplicable,
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Pass => write!(f, "pass"),
+            Verification::Fail => write!(f, "FAIL"),
+            Verification::NoInput => write!(f, "missing input"),
+            Verification::NoAcceptedAnswer => write!(f, "missing answer"),
+            Verification::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+// Compares the answers of every registered day with the ones accepted so far,
+// so that a refactoring of shared code cannot silently break a solved day
+fn verify(
+    args: &Args,
+    input_dir: &Path,
+    history_file: &Path,
+    year: Option<i32>,
+) -> anyhow::Result<()> {
+    let history = History::load(history_file)?;
+    let mut mismatches = Vec::new();
+
+    let rows = run_registered(args, input_dir, year)
+        .into_iter()
+        .map(|(year, day, run)| {
+            if args.format == Format::Json {
+                for record in run.records(year, day) {
+                    Record {
+                        expected: history.accepted_answer(year, day, record.part).map(String::from),
+                        ..record
+                    }
+                    .print();
                 }
             }
-        )*
 
-        None
+            let mut verifications = run.outcomes().into_iter().zip(1..).map(|(outcome, part)| {
+                match (history.accepted_answer(year, day, part), outcome) {
+                    (_, Outcome::NotApplicable) => Verification::NotApplicable,
+                    (None, _) => Verification::NoAcceptedAnswer,
+                    (Some(_), Outcome::MissingInput) => Verification::NoInput,
+                    (Some(accepted), Outcome::Answer(answer)) if accepted == answer => {
+                        Verification::Pass
+                    }
+                    (Some(accepted), outcome) => {
+                        mismatches.push(format!(
+                            "Part {part} of day {day} of year {year}: \
+                             got {outcome}, expected {accepted}"
+                        ));
+                        Verification::Fail
+                    }
+                }
+            });
+
+            (year, day, [verifications.next().unwrap(), verifications.next().unwrap()])
+        })
+        .collect::<Vec<_>>();
+
+    if args.format == Format::Text {
+        print_table(&rows);
+
+        let count = |predicate: fn(&Verification) -> bool| {
+            rows.iter()
+                .flat_map(|(_, _, verifications)| verifications.iter())
+                .filter(|verification| predicate(verification))
+                .count()
+        };
+
+        println!(
+            "\n{} passed, {} failed, {} missing inputs, {} missing answers",
+            count(|verification| matches!(verification, Verification::Pass)),
+            count(|verification| matches!(verification, Verification::Fail)),
+            count(|verification| matches!(verification, Verification::NoInput)),
+            count(|verification| matches!(verification, Verification::NoAcceptedAnswer)),
+        );
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} answers do not match the accepted ones:\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        ))
+    }
+}
+
+fn list_puzzles(input_dir: &Path, year: Option<i32>) {
+    let registrations = PUZZLES
+        .iter()
+        .filter(|registration| year.is_none_or(|year| year == registration.year))
+        .collect::<Vec<_>>();
+
+    let width = registrations
+        .iter()
+        .map(|registration| registration.title.len())
+        .chain(std::iter::once("Title".len()))
+        .max()
+        .unwrap_or(0);
+
+    println!("Year  Day  {:<width$}  Parts  Input", "Title");
+
+    for registration in registrations {
+        let parts = registration
+            .parts
+            .iter()
+            .map(|part| format!("{part}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input = if input_path(input_dir, registration.year, registration.day).exists() {
+            "cached"
+        } else {
+            "missing"
+        };
+
+        println!(
+            "{}  {:02}   {:<width$}  {parts:<5}  {input}",
+            registration.year, registration.day, registration.title
+        );
+    }
+}
+
+fn describe(
+    backend: &mut dyn Backend,
+    description_dir: &Path,
+    year: i32,
+    day: u32,
+    refresh: bool,
+) -> anyhow::Result<()> {
+    let path = description_path(description_dir, year, day);
+
+    if !refresh && path.exists() {
+        println!("The description is already cached in {}", path.display());
+        return Ok(());
+    }
+
+    let page = backend.get_puzzle_page(year, day)?;
+
+    if let Some(directory) = path.parent() {
+        create_dir_all(directory)?;
+    }
+    write(&path, to_markdown(&page))?;
+    println!("Saved the description in {}", path.display());
+
+    for (number, example) in (1..).zip(extract_examples(&page)) {
+        let example_path = example_path(description_dir, year, day, number);
+        write(&example_path, &example)?;
+
+        println!("\nExample {number}, saved in {}:\n{example}", example_path.display());
+    }
+
+    Ok(())
+}
+
+fn run_profiles(args: &Args, year: i32, day: u32) -> anyhow::Result<()> {
+    let registration = find_registration(year, day)?;
+    let mut mismatches = Vec::new();
+    let mut rows = Vec::new();
+
+    for profile in Profile::all(&args.profiles_dir)? {
+        let mut backend = make_backend(args, &profile)?;
+        let input = match Client::new(backend.as_mut(), args, &profile, year, day).get_input() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("No input for profile {}: {error}", profile.name);
+                let missing = DayRun::without_answers(registration.parts, Outcome::MissingInput)
+                    .outcomes()
+                    .map(|outcome| format!("{outcome}"));
+                rows.push((profile.name, missing));
+                continue;
+            }
+        };
+
+        let history = History::load(&profile.history_file)?;
+        let mut cells = run_both_parts(registration, &args.prepare_input(input))
+            .outcomes()
+            .into_iter()
+            .zip(1..)
+            .map(|(outcome, part)| {
+                match (history.accepted_answer(year, day, part), &outcome) {
+                    (None, _) | (_, Outcome::NotApplicable) => format!("{outcome}"),
+                    (Some(accepted), Outcome::Answer(answer)) if answer == accepted => {
+                        format!("{outcome} (accepted)")
+                    }
+                    (Some(accepted), _) => {
+                        mismatches.push(format!(
+                            "Part {part} for profile {}: got {outcome}, expected {accepted}",
+                            profile.name
+                        ));
+                        format!("{outcome} (expected {accepted})")
+                    }
+                }
+            });
+
+        rows.push((
+            profile.name.clone(),
+            [cells.next().unwrap(), cells.next().unwrap()],
+        ));
+    }
+
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(std::iter::once("Profile".len()))
+        .max()
+        .unwrap_or(0);
+    let width = rows
+        .iter()
+        .map(|(_, [part1, _])| part1.len())
+        .chain(std::iter::once("Part 1".len()))
+        .max()
+        .unwrap_or(0);
+
+    println!("{:<name_width$}  {:<width$}  Part 2", "Profile", "Part 1");
+
+    for (name, [part1, part2]) in rows.iter() {
+        println!("{name:<name_width$}  {part1:<width$}  {part2}");
     }
 
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} answers do not match the accepted ones:\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        ))
+    }
+}
+
+fn new_day(
+    args: &Args,
+    profile: &Profile,
+    year: i32,
+    day: u32,
+    src_dir: &Path,
+) -> anyhow::Result<()> {
+    let path = module_path(src_dir, year, day);
+
+    if path.exists() {
+        return Err(anyhow!("{} already exists", path.display()));
+    }
+
+    let mut backend = make_backend(args, profile)?;
+
+    // The puzzle may not be unlocked yet, in which case the template is
+    // still useful
+    let (title, examples) = match backend.get_puzzle_page(year, day) {
+        Ok(page) => (
+            title(&page).unwrap_or_else(|| format!("Day {day}")),
+            extract_examples(&page).into_iter().take(1).collect(),
+        ),
+        Err(error) => {
+            eprintln!("Not able to get the puzzle page: {error}");
+            (format!("Day {day}"), Vec::new())
+        }
     };
+
+    if let Some(directory) = path.parent() {
+        create_dir_all(directory)?;
+    }
+    write(&path, day_module(year, day, &title, &examples))?;
+    println!("Created {}, it is registered on the next build", path.display());
+
+    if args.local_dir.is_some() || profile.cookie_file.exists() {
+        match Client::new(backend.as_mut(), args, profile, year, day).get_input() {
+            Ok(_) => println!(
+                "Cached the input in {}",
+                input_path(&profile.input_dir, year, day).display()
+            ),
+            Err(error) => eprintln!("Not able to get the input: {error}"),
+        }
+    }
+
+    Ok(())
 }
 
-// Defines the puzzle_runner function
-make_puzzle_runner![
-    (2022, 01, aoc22::day01::Day01),
-
-    (2024, 01, aoc24::day01::Day01),
-    (2024, 02, aoc24::day02::Day02),
-    (2024, 03, aoc24::day03::Day03),
-    (2024, 04, aoc24::day04::Day04),
-    (2024, 05, aoc24::day05::Day05),
-    (2024, 06, aoc24::day06::Day06),
-    (2024, 07, aoc24::day07::Day07),
-    (2024, 08, aoc24::day08::Day08),
-    (2024, 09, aoc24::day09::Day09),
-    (2024, 10, aoc24::day10::Day10),
-    (2024, 11, aoc24::day11::Day11),
-    (2024, 12, aoc24::day12::Day12),
-    (2024, 13, aoc24::day13::Day13),
-    (2024, 14, aoc24::day14::Day14),
-    (2024, 15, aoc24::day15::Day15),
-    (2024, 16, aoc24::day16::Day16),
-    (2024, 17, aoc24::day17::Day17),
-    (2024, 18, aoc24::day18::Day18),
-    (2024, 19, aoc24::day19::Day19),
-    (2024, 20, aoc24::day20::Day20),
-    (2024, 21, aoc24::day21::Day21),
-    (2024, 22, aoc24::day22::Day22),
-    (2024, 23, aoc24::day23::Day23),
-    (2024, 24, aoc24::day24::Day24),
-    (2024, 25, aoc24::day25::Day25)
-];
+// Runs until interrupted. The answers are compared with those of the
+// previous run, to see at a glance what an edit of the input changed
+fn watch(args: &Args, client: &mut Client<'_>) -> anyhow::Result<()> {
+    let (year, day) = (client.year, client.day);
+    let registration = find_registration(year, day)?;
+
+    // Downloads the input if it is not cached yet
+    client.get_input()?;
+    let path = match &args.input_file {
+        Some(path) => path.clone(),
+        None => input_path(&client.profile.input_dir, year, day),
+    };
+
+    let mut watcher = FileWatcher::new(&path);
+    let mut previous: Option<[String; 2]> = None;
+
+    loop {
+        // The file may be missing for a moment while it is being saved
+        let input = match read_to_string(&path) {
+            Ok(input) => args.prepare_input(input),
+            Err(error) => {
+                eprintln!("Cannot read {}: {error}", path.display());
+                watcher.wait_for_change();
+                continue;
+            }
+        };
+        let run = run_both_parts(registration, &input);
+
+        if args.format == Format::Json {
+            for record in run.records(year, day) {
+                record.print();
+            }
+        } else if let Some(parse_time) = run.parse_time {
+            println!("Parsed in {parse_time:.2?}");
+        }
+
+        let answers = run.parts.map(|(outcome, time)| (format!("{outcome}"), time));
+
+        if args.format == Format::Text {
+            for (part, (answer, time)) in (1..).zip(answers.iter()) {
+                let answer = compare(
+                    previous.as_ref().map(|previous| previous[part - 1].as_str()),
+                    answer,
+                );
+
+                match time {
+                    Some(time) => println!("Part {part} answer is {answer} (solved in {time:.2?})"),
+                    None => println!("Part {part} answer is {answer}"),
+                }
+            }
+        }
+
+        previous = Some(answers.map(|(answer, _)| answer));
+
+        args.inform(format!("\nWatching {} for changes", path.display()));
+        watcher.wait_for_change();
+    }
+}
+
+fn show_history(history_file: &Path, year: Option<i32>, day: Option<u32>) -> anyhow::Result<()> {
+    let history = History::load(history_file)?;
+
+    for submission in history.submissions().iter().filter(|submission| {
+        year.is_none_or(|year| year == submission.year)
+            && day.is_none_or(|day| day == submission.day)
+    }) {
+        println!(
+            "{} {:02} part {}  {:<11}  {}",
+            submission.year, submission.day, submission.part, submission.verdict, submission.answer
+        );
+    }
+
+    Ok(())
+}
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    let mut client = Client::new(&args);
+    let profile = args.profile();
+
+    match args.command {
+        Some(Command::All { year }) => {
+            run_all(&args, &profile.input_dir, year);
+            return Ok(());
+        }
+        Some(Command::Verify { year }) => {
+            return verify(&args, &profile.input_dir, &profile.history_file, year);
+        }
+        Some(Command::List { year }) => {
+            list_puzzles(&profile.input_dir, year);
+            return Ok(());
+        }
+        Some(Command::History { year, day }) => {
+            return show_history(&profile.history_file, year, day)
+        }
+        Some(Command::Describe { year, day, refresh }) => {
+            let mut backend = make_backend(&args, &profile)?;
+
+            return describe(backend.as_mut(), &args.description_dir, year, day, refresh);
+        }
+        Some(Command::Profiles { year, day }) => return run_profiles(&args, year, day),
+        Some(Command::New {
+            year,
+            day,
+            ref src_dir,
+        }) => return new_day(&args, &profile, year, day, src_dir),
+        Some(Command::Serve {
+            ref fixtures,
+            ref address,
+            cooldown,
+        }) => {
+            return StandInServer::new(fixtures, Duration::from_secs(cooldown)).serve(address);
+        }
+        None => (),
+    }
+
+    run(&args, &profile, make_backend(&args, &profile)?.as_mut())
+}
+
+fn make_backend(args: &Args, profile: &Profile) -> anyhow::Result<Box<dyn Backend>> {
+    Ok(match &args.local_dir {
+        Some(directory) => Box::new(DirectoryBackend::new(directory)),
+        None => Box::new(AocBackend::new(&profile.cookie_file, &args.base_url)?),
+    })
+}
+
+// Gets the input, solves the requested parts and submits the answers
+fn run(args: &Args, profile: &Profile, backend: &mut dyn Backend) -> anyhow::Result<()> {
+    // These are required by clap when no subcommand is given, except for the
+    // part when watching
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+
+    let mut client = Client::new(backend, args, profile, year, day);
 
-    let input = client.get_input()?;
+    if args.watch {
+        return watch(args, &mut client);
+    }
+
+    let example = args
+        .example
+        .map(|number| find_example(year, day, number))
+        .transpose()?;
+
+    let input = args.prepare_input(match example {
+        Some(example) => String::from(example.input),
+        None if args.wait => {
+            wait_for_unlock(year, day);
+            client.get_input_once_unlocked()?
+        }
+        None => client.get_input()?,
+    });
 
-    match puzzle_runner(args.year, args.day, args.part, &input) {
-        None => Err(anyhow!(
-            "Not able to compute an answer for part {} of day {} of year {}",
-            args.part,
-            args.day,
-            args.year
-        )),
-        Some(value) => {
-            client.submit_answer(args.part, value)?;
-            Ok(())
+    // The part is required by clap unless waiting, in which case getting
+    // the input may be all there is to do
+    let parts = match args.part {
+        Some(_) if args.wait && find_registration(year, day).is_err() => {
+            args.inform(format!(
+                "Day {day} of year {year} is not implemented yet, its input is cached"
+            ));
+            return Ok(());
+        }
+        Some(part) => part.numbers(),
+        None => {
+            args.inform(format!("The input of day {day} of year {year} is cached"));
+            return Ok(());
         }
+    };
+
+    let mut parse_timings = Timings::default();
+    let mut part_timings = vec![Timings::default(); parts.len()];
+    let mut answers = Vec::new();
+
+    for _ in 0..args.repeat {
+        let solution = match puzzle_runner(year, day, parts, &input) {
+            Ok(solution) => solution,
+            Err(error) => {
+                if args.format == Format::Json {
+                    for &part in parts {
+                        Record {
+                            error: Some(format!("{error}")),
+                            ..Record::new(year, day, part)
+                        }
+                        .print();
+                    }
+                }
+
+                return Err(anyhow!(
+                    "Not able to compute an answer for day {} of year {}: {}",
+                    day,
+                    year,
+                    error.show(&input)
+                ));
+            }
+        };
+
+        parse_timings.push(solution.parse_time);
+        for (timings, (_, time)) in part_timings.iter_mut().zip(solution.answers.iter()) {
+            timings.push(*time);
+        }
+
+        answers = solution.answers;
+    }
+
+    let mut records = parts
+        .iter()
+        .copied()
+        .zip(answers.iter())
+        .zip(part_timings.iter())
+        .map(|((part, (answer, _)), timings)| {
+            let record = Record {
+                expected: example.and_then(|example| example.answer(part)).map(String::from),
+                ..Record::new(year, day, part).timed(&parse_timings, timings)
+            };
+
+            match answer {
+                Ok(answer) => Record {
+                    answer: Some(answer.clone()),
+                    ..record
+                },
+                Err(error) => Record {
+                    error: Some(format!("{error}")),
+                    ..record
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let answers = parts
+        .iter()
+        .copied()
+        .zip(answers)
+        .map(|(part, (answer, _))| {
+            answer.map_err(|error| {
+                anyhow!(
+                    "Not able to compute an answer for part {} of day {} of year {}: {}",
+                    part,
+                    day,
+                    year,
+                    error.show(&input)
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if args.format == Format::Text {
+        print_answers(parts, &answers, &parse_timings, &part_timings, example);
+    }
+
+    let submit = !args.no_submit && example.is_none() && (args.submit || !args.wait);
+    let result = submit_answers(&mut client, submit, answers, &mut records);
+
+    if args.format == Format::Json {
+        for record in records.iter() {
+            record.print();
+        }
+    }
+
+    result
+}
+
+fn print_answers(
+    parts: &[i64],
+    answers: &[anyhow::Result<String>],
+    parse_timings: &Timings,
+    part_timings: &[Timings],
+    example: Option<&Example>,
+) {
+    println!("Parsed in {parse_timings}");
+
+    for ((part, answer), timings) in parts.iter().zip(answers.iter()).zip(part_timings.iter()) {
+        match answer {
+            Ok(answer) if parts.len() > 1 => {
+                println!("Part {part} answer is {answer} (solved in {timings})")
+            }
+            Ok(answer) => println!("Answer is {answer} (solved in {timings})"),
+            Err(_) => (),
+        }
+
+        let expected = example.and_then(|example| example.answer(*part));
+
+        if let (Some(expected), Ok(answer)) = (expected, answer) {
+            if expected != answer {
+                println!("The example expects {expected} for part {part}");
+            }
+        }
+    }
+}
+
+// Stops at the first answer which could not be computed, and records the
+// verdict of each submitted answer
+fn submit_answers(
+    client: &mut Client<'_>,
+    submit: bool,
+    answers: Vec<anyhow::Result<String>>,
+    records: &mut [Record],
+) -> anyhow::Result<()> {
+    for (answer, record) in answers.into_iter().zip(records.iter_mut()) {
+        let answer = answer?;
+
+        if !submit {
+            continue;
+        }
+
+        let verdict = client
+            .submit_answer(record.part, &answer)
+            .inspect_err(|error| record.error = Some(format!("{error}")))?;
+        record.verdict = Some(verdict);
+
+        // Part 2 only unlocks once part 1 is solved, so there is no point in
+        // submitting it before
+        if verdict != Verdict::Correct {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backend::FakeBackend;
+
+    // The first example of 2024 day 1, whose answers are 11 and 31
+    fn fake_backend(part1: &str, part2: &str) -> FakeBackend {
+        let mut backend = FakeBackend::default();
+
+        backend
+            .inputs
+            .insert((2024, 1), String::from(aoc24::day01::EXAMPLES[0].input));
+        backend.answers.insert((2024, 1, 1), String::from(part1));
+        backend.answers.insert((2024, 1, 2), String::from(part2));
+
+        backend
+    }
+
+    fn args(directory: &Path, part: &str) -> Args {
+        Args::parse_from([
+            "advent-of-code",
+            "--year=2024",
+            "--day=1",
+            &format!("--part={part}"),
+            &format!("--profiles-dir={}", directory.display()),
+            "--profile=tester",
+        ])
+    }
+
+    fn profile(directory: &Path) -> Profile {
+        Profile::named(directory, "tester")
+    }
+
+    fn submitted(backend: &FakeBackend) -> Vec<(i64, &str)> {
+        backend
+            .submissions
+            .iter()
+            .map(|(_, _, part, answer)| (*part, answer.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn submits_both_parts_and_records_the_verdicts() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("11", "31");
+
+        run(&args(directory.path(), "both"), &profile(directory.path()), &mut backend).unwrap();
+
+        assert_eq!(submitted(&backend), [(1, "11"), (2, "31")]);
+
+        let history = History::load(&profile(directory.path()).history_file).unwrap();
+        assert_eq!(history.accepted_answer(2024, 1, 1), Some("11"));
+        assert_eq!(history.accepted_answer(2024, 1, 2), Some("31"));
+    }
+
+    #[test]
+    fn caches_the_input() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("11", "31");
+
+        run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend).unwrap();
+        backend.inputs.clear();
+        run(&args(directory.path(), "2"), &profile(directory.path()), &mut backend).unwrap();
+
+        assert_eq!(submitted(&backend), [(1, "11"), (2, "31")]);
+        assert!(input_path(&profile(directory.path()).input_dir, 2024, 1).exists());
+    }
+
+    #[test]
+    fn does_not_submit_part_2_after_a_wrong_part_1() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("12", "31");
+
+        run(&args(directory.path(), "both"), &profile(directory.path()), &mut backend).unwrap();
+
+        assert_eq!(submitted(&backend), [(1, "11")]);
+
+        let history = History::load(&profile(directory.path()).history_file).unwrap();
+        assert_eq!(history.submissions()[0].verdict, Verdict::TooLow);
+    }
+
+    #[test]
+    fn records_the_verdicts_of_the_submitted_answers() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("12", "31");
+        let (args, profile) = (args(directory.path(), "both"), profile(directory.path()));
+        let mut client = Client::new(&mut backend, &args, &profile, 2024, 1);
+        let mut records = [Record::new(2024, 1, 1), Record::new(2024, 1, 2)];
+
+        let answers = vec![Ok(String::from("11")), Ok(String::from("31"))];
+        submit_answers(&mut client, true, answers, &mut records).unwrap();
+
+        assert_eq!(records[0].verdict, Some(Verdict::TooLow));
+        assert_eq!(records[1].verdict, None);
+    }
+
+    #[test]
+    fn refuses_to_submit_a_known_wrong_answer_again() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("12", "31");
+
+        run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend).unwrap();
+
+        let result = run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend);
+        assert!(result.is_err());
+        assert_eq!(submitted(&backend), [(1, "11")]);
+    }
+
+    #[test]
+    fn does_not_submit_an_already_accepted_answer() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("11", "31");
+
+        run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend).unwrap();
+        run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend).unwrap();
+
+        assert_eq!(submitted(&backend), [(1, "11")]);
+    }
+
+    #[test]
+    fn only_solves_the_parts_of_a_day() {
+        let registration = find_registration(2024, 25).unwrap();
+        let run = run_both_parts(registration, aoc24::day25::EXAMPLES[0].input);
+
+        assert!(matches!(&run.parts[0].0, Outcome::Answer(answer) if answer == "3"));
+        assert!(matches!(run.parts[1], (Outcome::NotApplicable, None)));
+        assert_eq!(run.records(2024, 25).len(), 1);
+    }
+
+    #[test]
+    fn does_not_submit_during_a_cooldown() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("11", "31");
+
+        Cooldown::new(&profile(directory.path()).cooldown_file)
+            .start(Duration::from_secs(60))
+            .unwrap();
+
+        let result = run(&args(directory.path(), "1"), &profile(directory.path()), &mut backend);
+        assert!(result.is_err());
+        assert!(backend.submissions.is_empty());
+    }
+
+    #[test]
+    fn only_submits_after_waiting_when_asked_to() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = fake_backend("11", "31");
+        let profile = profile(directory.path());
+        let waiting = |extra: &[&str]| {
+            let mut words = vec![
+                String::from("advent-of-code"),
+                String::from("--year=2024"),
+                String::from("--day=1"),
+                String::from("--wait"),
+                format!("--profiles-dir={}", directory.path().display()),
+                String::from("--profile=tester"),
+            ];
+            words.extend(extra.iter().map(|word| String::from(*word)));
+
+            Args::parse_from(words)
+        };
+
+        run(&waiting(&[]), &profile, &mut backend).unwrap();
+        assert!(input_path(&profile.input_dir, 2024, 1).exists());
+
+        run(&waiting(&["--part=both"]), &profile, &mut backend).unwrap();
+        assert!(backend.submissions.is_empty());
+
+        run(&waiting(&["--part=1", "--submit"]), &profile, &mut backend).unwrap();
+        assert_eq!(submitted(&backend), [(1, "11")]);
+
+        assert!(Args::try_parse_from(["advent-of-code", "--year=2024", "--day=1"]).is_err());
+        let submit = ["advent-of-code", "--year=2024", "--day=1", "--part=1", "--submit"];
+        assert!(Args::try_parse_from(submit).is_err());
+    }
+
+    #[test]
+    fn does_not_submit_again_after_an_unknown_wait() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend = FakeBackend {
+            outcome: Some(String::from("You gave an answer too recently.")),
+            ..fake_backend("11", "31")
+        };
+        let args = Args {
+            wait_cooldown: true,
+            ..args(directory.path(), "1")
+        };
+
+        assert!(run(&args, &profile(directory.path()), &mut backend).is_err());
+        assert_eq!(submitted(&backend), [(1, "11")]);
     }
 }
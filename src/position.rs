@@ -1,4 +1,9 @@
-use std::ops::{Add, Sub};
+use std::{
+    iter::once,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::puzzle::{Location, PuzzleError, PuzzleResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Direction {
@@ -53,6 +58,84 @@ impl Direction {
     }
 }
 
+// The eight directions of a compass, for puzzles where moving diagonally
+// counts as well
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    // Clockwise, starting from the north
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    pub fn delta(self) -> Position {
+        match self {
+            Compass::North => Position { x: 0, y: -1 },
+            Compass::NorthEast => Position { x: 1, y: -1 },
+            Compass::East => Position { x: 1, y: 0 },
+            Compass::SouthEast => Position { x: 1, y: 1 },
+            Compass::South => Position { x: 0, y: 1 },
+            Compass::SouthWest => Position { x: -1, y: 1 },
+            Compass::West => Position { x: -1, y: 0 },
+            Compass::NorthWest => Position { x: -1, y: -1 },
+        }
+    }
+
+    // Turns clockwise by 45 degrees for each eighth, or anticlockwise when
+    // negative
+    pub fn rotate(self, eighths: i64) -> Compass {
+        Compass::ALL[(self as i64 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn reverse(self) -> Compass {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+impl TryFrom<Compass> for Direction {
+    type Error = PuzzleError;
+
+    fn try_from(compass: Compass) -> Result<Self, Self::Error> {
+        match compass {
+            Compass::North => Ok(Direction::Up),
+            Compass::East => Ok(Direction::Right),
+            Compass::South => Ok(Direction::Down),
+            Compass::West => Ok(Direction::Left),
+            diagonal => Err(PuzzleError::new(format!(
+                "{diagonal:?} is not one of the four directions"
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Position {
     pub x: i64,
@@ -81,7 +164,83 @@ impl Sub<Position> for Position {
     }
 }
 
+impl Mul<i64> for Position {
+    type Output = Position;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Position {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Neg for Position {
+    type Output = Position;
+
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl AddAssign<Position> for Position {
+    fn add_assign(&mut self, rhs: Position) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Position> for Position {
+    fn sub_assign(&mut self, rhs: Position) {
+        *self = *self - rhs;
+    }
+}
+
 impl Position {
+    // The number of steps between two positions when moving in the four
+    // directions
+    pub fn manhattan_distance(self, other: Position) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // The number of steps between two positions when moving diagonally as well
+    pub fn chebyshev_distance(self, other: Position) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // Turns around the origin by 90 degrees for each quarter turn, clockwise
+    // on the screen where y goes down, or anticlockwise when negative
+    pub fn rotate(self, quarter_turns: i64) -> Position {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => Position {
+                x: -self.y,
+                y: self.x,
+            },
+            2 => -self,
+            _ => Position {
+                x: self.y,
+                y: -self.x,
+            },
+        }
+    }
+
+    // Every position at a Manhattan distance of at most `radius`, starting
+    // with this one and going outwards. Each ring is made of a quarter which
+    // is turned around this position
+    pub fn within_distance(self, radius: i64) -> impl Iterator<Item = Position> {
+        once(self).chain((1..=radius).flat_map(move |distance| {
+            (0..4).flat_map(move |quarter_turns| {
+                (0..distance).map(move |x| {
+                    self + Position {
+                        x,
+                        y: distance - x,
+                    }
+                    .rotate(quarter_turns)
+                })
+            })
+        }))
+    }
+
     pub fn neighbors_with_directions(self) -> [(Self, Direction); 4] {
         let vectors = [
             (Position { x: 1, y: 0 }, Direction::Right),
@@ -97,15 +256,19 @@ impl Position {
         self.neighbors_with_directions().map(|(pos, _)| pos)
     }
 
-    pub fn diagonal_neighbors(self) -> [Self; 4] {
-        let vectors = [
-            Position { x: 1, y: 1 },
-            Position { x: 1, y: -1 },
-            Position { x: -1, y: -1 },
-            Position { x: -1, y: 1 },
-        ];
+    // In the order of `Compass::ALL`
+    pub fn neighbors8(self) -> [Self; 8] {
+        Compass::ALL.map(|direction| self + direction.delta())
+    }
 
-        vectors.map(|diff| self + diff)
+    pub fn diagonal_neighbors(self) -> [Self; 4] {
+        [
+            Compass::SouthEast,
+            Compass::NorthEast,
+            Compass::NorthWest,
+            Compass::SouthWest,
+        ]
+        .map(|direction| self + direction.delta())
     }
 }
 
@@ -121,3 +284,266 @@ pub fn read_map(input: &str, mut map_element: impl FnMut(Position, char)) {
         }
     }
 }
+
+// The rectangle between two corners, both included, such as the extent of a
+// map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Position,
+    pub max: Position,
+}
+
+impl Bounds {
+    // Starting from the origin, like the maps read with `read_map`
+    pub const fn with_size(width: i64, height: i64) -> Bounds {
+        Bounds {
+            min: Position { x: 0, y: 0 },
+            max: Position {
+                x: width - 1,
+                y: height - 1,
+            },
+        }
+    }
+
+    // The smallest bounds holding every character of the map, which hold no
+    // position at all when the map is empty
+    pub fn of_map(input: &str) -> Bounds {
+        let mut max = Position { x: -1, y: -1 };
+
+        read_map(input, |position, _| {
+            max.x = max.x.max(position.x);
+            max.y = max.y.max(position.y);
+        });
+
+        Bounds {
+            min: Position { x: 0, y: 0 },
+            max,
+        }
+    }
+
+    pub fn width(self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    // Bounds without any position contain nothing, even though they still
+    // clamp positions to one of their corners
+    pub fn contains(self, position: Position) -> bool {
+        self.width() > 0 && self.height() > 0 && self.clamp(position) == position
+    }
+
+    // The position inside the bounds which is the closest to `position`
+    pub fn clamp(self, position: Position) -> Position {
+        Position {
+            x: position.x.max(self.min.x).min(self.max.x),
+            y: position.y.max(self.min.y).min(self.max.y),
+        }
+    }
+
+    // Brings back a position which left the bounds on the opposite side, as if
+    // the edges were stitched together
+    pub fn wrap(self, position: Position) -> Position {
+        Position {
+            x: self.min.x + (position.x - self.min.x).rem_euclid(self.width()),
+            y: self.min.y + (position.y - self.min.y).rem_euclid(self.height()),
+        }
+    }
+
+    // Row by row
+    pub fn positions(self) -> impl Iterator<Item = Position> {
+        (self.min.y..=self.max.y)
+            .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Position { x, y }))
+    }
+}
+
+// A rectangular map stored row by row, which is much faster to look up than a
+// hash map of positions when most positions hold something. Positions are
+// the same as with `read_map`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // Every line of the map must be as long as the first one
+    pub fn from_map(
+        input: &str,
+        mut map_element: impl FnMut(Position, char) -> PuzzleResult<T>,
+    ) -> PuzzleResult<Grid<T>> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        let mut error = None;
+
+        read_map(input, |position, c| {
+            if error.is_none() {
+                match map_element(position, c) {
+                    Ok(element) => cells.push(element),
+                    Err(element_error) => error = Some(element_error),
+                }
+            }
+        });
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        for (y, line) in input.lines().enumerate() {
+            let length = line.chars().count();
+
+            if length != width {
+                let location = Location {
+                    line: y + 1,
+                    column: length.min(width) + 1,
+                };
+
+                return Err(PuzzleError::at(
+                    location,
+                    format!("Expected a line of {width} characters like the first one"),
+                ));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: cells.len() / width.max(1),
+            cells,
+        })
+    }
+
+    fn offset(&self, position: Position) -> Option<usize> {
+        let (x, y) = (usize::try_from(position.x).ok()?, usize::try_from(position.y).ok()?);
+
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    // Row by row, which does not borrow the grid
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        Bounds::with_size(self.width as i64, self.height as i64).positions()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The neighbours which are inside the grid
+    pub fn neighbors(&self, position: Position) -> impl Iterator<Item = (Position, &T)> {
+        position
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn reads_a_grid() {
+        let grid = Grid::from_map("ab\ncd\n", |_, c| Ok(c)).unwrap();
+        let at = |x, y| Position { x, y };
+
+        assert_eq!(grid[at(1, 0)], 'b');
+        assert_eq!(grid.get(at(2, 0)), None);
+        assert_eq!(grid.get(at(0, -1)), None);
+        assert_eq!(
+            grid.neighbors(at(0, 1)).collect::<Vec<_>>(),
+            [(at(1, 1), &'d'), (at(0, 0), &'a')]
+        );
+        assert_eq!(grid.iter().last(), Some((at(1, 1), &'d')));
+
+        let error = Grid::from_map("ab\nc\n", |_, c| Ok(c)).unwrap_err();
+        assert_eq!(error.location(), Some(Location { line: 2, column: 2 }));
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let bounds = Bounds::of_map("...\n...\n");
+        let at = |x, y| Position { x, y };
+
+        assert_eq!(bounds, Bounds::with_size(3, 2));
+        assert!(bounds.contains(at(2, 1)));
+        assert!(!bounds.contains(at(3, 1)));
+        assert!(!bounds.contains(at(0, -1)));
+        assert_eq!(bounds.clamp(at(5, -3)), at(2, 0));
+        assert_eq!(bounds.wrap(at(-1, 5)), at(2, 1));
+        assert_eq!(bounds.positions().nth(4), Some(at(1, 1)));
+        assert_eq!(bounds.positions().count(), 6);
+
+        let empty = Bounds::of_map("");
+        assert_eq!(empty, Bounds::with_size(0, 0));
+        assert_eq!(empty.positions().count(), 0);
+        assert!(!empty.contains(at(0, 0)));
+        assert!(!empty.contains(at(-1, -1)));
+    }
+
+    #[test]
+    fn measures_and_turns_positions() {
+        let at = |x, y| Position { x, y };
+
+        assert_eq!(at(1, -2) * 3, at(3, -6));
+        assert_eq!(-at(1, -2), at(-1, 2));
+        assert_eq!(at(2, 5).manhattan_distance(at(-1, 1)), 7);
+        assert_eq!(at(2, 5).chebyshev_distance(at(-1, 1)), 4);
+        assert_eq!(Direction::Up.delta().rotate(1), Direction::Right.delta());
+        assert_eq!(at(2, 1).rotate(-1), at(1, -2));
+        assert_eq!(at(2, 1).rotate(6), at(-2, -1));
+
+        let mut position = at(1, 1);
+        position += at(2, 0);
+        position -= at(0, 3);
+        assert_eq!(position, at(3, -2));
+
+        let around = at(3, -2).within_distance(2).collect::<Vec<_>>();
+        assert_eq!(around.len(), 13);
+        assert_eq!(around.iter().collect::<HashSet<_>>().len(), around.len());
+        assert!(around.iter().all(|&other| other.manhattan_distance(position) <= 2));
+    }
+
+    #[test]
+    fn turns_around_the_compass() {
+        assert_eq!(Compass::North.rotate(1), Compass::NorthEast);
+        assert_eq!(Compass::North.rotate(-2), Compass::West);
+        assert_eq!(Compass::SouthWest.reverse(), Compass::NorthEast);
+
+        for direction in Direction::ALL {
+            let compass = Compass::from(direction);
+
+            assert_eq!(compass.delta(), direction.delta());
+            assert_eq!(Direction::try_from(compass).ok(), Some(direction));
+        }
+
+        assert!(Direction::try_from(Compass::NorthWest).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+// Everything that belongs to one AoC account, as inputs differ between
+// accounts and so do the answers
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub cookie_file: PathBuf,
+    pub input_dir: PathBuf,
+    pub history_file: PathBuf,
+    pub cooldown_file: PathBuf,
+}
+
+impl Profile {
+    // The files used when no profile is chosen
+    pub fn default_profile() -> Profile {
+        Profile {
+            name: String::from("default"),
+            cookie_file: PathBuf::from(".cookie"),
+            input_dir: PathBuf::from("inputs"),
+            history_file: PathBuf::from("history.tsv"),
+            cooldown_file: PathBuf::from(".cooldown"),
+        }
+    }
+
+    // A named profile keeps its `cookie`, its `inputs` directory, its
+    // `history.tsv` and its `cooldown` in `<profiles directory>/<name>`
+    pub fn named(profiles_dir: &Path, name: &str) -> Profile {
+        let directory = profiles_dir.join(name);
+
+        Profile {
+            name: String::from(name),
+            cookie_file: directory.join("cookie"),
+            input_dir: directory.join("inputs"),
+            history_file: directory.join("history.tsv"),
+            cooldown_file: directory.join("cooldown"),
+        }
+    }
+
+    // Every subdirectory of the profiles directory is a profile
+    pub fn all(profiles_dir: &Path) -> anyhow::Result<Vec<Profile>> {
+        let mut names = Vec::new();
+
+        for entry in read_dir(profiles_dir)? {
+            let entry = entry?;
+
+            if entry.file_type()?.is_dir() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+
+        names.sort();
+
+        Ok(names
+            .iter()
+            .map(|name| Profile::named(profiles_dir, name))
+            .collect())
+    }
+}
@@ -1,11 +1,152 @@
 use std::fmt::Display;
 
-pub trait Puzzle: Sized {
-    type Output: Display; 
-    
-    fn parse(input: &str) -> Option<Self>;
+use crate::position::Position;
 
-    fn part1(self) -> Option<Self::Output>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    // Finds where `fragment` starts in `input`. This only works if `fragment`
+    // was sliced out of `input`, which is the case for anything obtained
+    // through `lines`, `split` and friends
+    pub fn of(input: &str, fragment: &str) -> Option<Location> {
+        let offset = (fragment.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+        let before = input.get(..offset)?;
+
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+// Positions read with `read_map` start at 0 while lines and columns start at 1
+impl From<Position> for Location {
+    fn from(position: Position) -> Self {
+        Location {
+            line: position.y as usize + 1,
+            column: position.x as usize + 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PuzzleError {
+    location: Option<Location>,
+    message: String,
+}
+
+impl PuzzleError {
+    pub fn new(message: impl Into<String>) -> Self {
+        PuzzleError {
+            location: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(location: Location, message: impl Into<String>) -> Self {
+        PuzzleError {
+            location: Some(location),
+            message: message.into(),
+        }
+    }
+
+    // Points at `fragment`, see `Location::of`
+    pub fn in_input(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        PuzzleError {
+            location: Location::of(input, fragment),
+            message: message.into(),
+        }
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+
+    // Renders the error along with the offending line of the input and a
+    // marker under the offending column
+    pub fn show(&self, input: &str) -> String {
+        match self.location {
+            None => format!("{self}"),
+            Some(Location { line, column }) => {
+                let offending_line = input.lines().nth(line - 1).unwrap_or("");
+
+                format!(
+                    "{self}\n    {offending_line}\n    {:>column$}",
+                    "^",
+                    column = column
+                )
+            }
+        }
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            None => write!(f, "{}", self.message),
+            Some(Location { line, column }) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+pub trait Context<T> {
+    fn context(self, message: impl Into<String>) -> PuzzleResult<T>;
+
+    fn context_at(self, input: &str, fragment: &str, message: impl Into<String>)
+        -> PuzzleResult<T>;
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context(self, message: impl Into<String>) -> PuzzleResult<T> {
+        self.ok_or_else(|| PuzzleError::new(message))
+    }
+
+    fn context_at(
+        self,
+        input: &str,
+        fragment: &str,
+        message: impl Into<String>,
+    ) -> PuzzleResult<T> {
+        self.ok_or_else(|| PuzzleError::in_input(input, fragment, message))
+    }
+}
+
+impl<T, E: Display> Context<T> for Result<T, E> {
+    fn context(self, message: impl Into<String>) -> PuzzleResult<T> {
+        self.map_err(|error| PuzzleError::new(format!("{} ({error})", message.into())))
+    }
+
+    fn context_at(
+        self,
+        input: &str,
+        fragment: &str,
+        message: impl Into<String>,
+    ) -> PuzzleResult<T> {
+        self.map_err(|error| {
+            PuzzleError::in_input(input, fragment, format!("{} ({error})", message.into()))
+        })
+    }
+}
+
+pub trait Puzzle: Sized + Clone {
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> PuzzleResult<Self>;
+
+    fn part1(self) -> PuzzleResult<Self::Output1>;
 
-    fn part2(self) -> Option<Self::Output>;
+    fn part2(self) -> PuzzleResult<Self::Output2>;
 }
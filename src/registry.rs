@@ -0,0 +1,162 @@
+use std::time::{Duration, Instant};
+
+use crate::puzzle::{Puzzle, PuzzleError, PuzzleResult};
+
+pub struct Solution {
+    pub parse_time: Duration,
+    pub answers: Vec<(PuzzleResult<String>, Duration)>,
+}
+
+// The input is parsed once, then each requested part gets its own copy of the
+// parsed puzzle. Copying the puzzle is not counted in the time of the part
+pub type Solver = fn(&[i64], &str) -> PuzzleResult<Solution>;
+
+pub fn solve<P: Puzzle>(parts: &[i64], input: &str) -> PuzzleResult<Solution> {
+    let start = Instant::now();
+    let puzzle = P::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let puzzle = puzzle.clone();
+            let start = Instant::now();
+
+            let answer = match part {
+                1 => puzzle.part1().map(|answer| format!("{answer}")),
+                2 => puzzle.part2().map(|answer| format!("{answer}")),
+                _ => Err(PuzzleError::new(format!("There is no part {part}"))),
+            };
+
+            (answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Solution {
+        parse_time,
+        answers,
+    })
+}
+
+// A small input given in the puzzle description, along with the answers it
+// is known to produce
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: i64) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+// Solves an example and compares the answers to the expected ones, for the
+// tests generated by `examples!`
+#[cfg(test)]
+pub fn check_example(solver: Solver, example: &Example) {
+    let expectations = [1, 2]
+        .into_iter()
+        .filter_map(|part| Some((part, example.answer(part)?)))
+        .collect::<Vec<_>>();
+    let parts = expectations.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+
+    let solution = solver(&parts, example.input)
+        .unwrap_or_else(|error| panic!("{}", error.show(example.input)));
+
+    for ((part, expected), (answer, _)) in expectations.into_iter().zip(solution.answers) {
+        let answer = answer.unwrap_or_else(|error| panic!("{}", error.show(example.input)));
+
+        assert_eq!(answer, expected, "wrong answer for part {part}");
+    }
+}
+
+// What a day tells about itself through `register_puzzle!`. The build script
+// collects the registration of every `src/aocYY/dayNN.rs` into `PUZZLES`
+pub struct Registration {
+    pub year: i32,
+    pub day: u32,
+    pub title: &'static str,
+    pub parts: &'static [i64],
+    pub examples: &'static [Example],
+    pub solver: Solver,
+}
+
+// Both parts are assumed to be solved unless told otherwise, e.g. the last
+// day which has no second part. The day must also declare its `EXAMPLES`
+// with `examples!`
+macro_rules! register_puzzle {
+    ($puzzle:ty, year = $year:literal, day = $day:literal, title = $title:literal) => {
+        register_puzzle!($puzzle, year = $year, day = $day, title = $title, parts = [1, 2]);
+    };
+    (
+        $puzzle:ty,
+        year = $year:literal,
+        day = $day:literal,
+        title = $title:literal,
+        parts = [$($part:literal),*]
+    ) => {
+        pub const REGISTRATION: $crate::registry::Registration = $crate::registry::Registration {
+            year: $year,
+            day: $day,
+            title: $title,
+            parts: &[$($part),*],
+            examples: EXAMPLES,
+            solver: $crate::registry::solve::<$puzzle>,
+        };
+    };
+}
+
+// Declares the `EXAMPLES` of a day and a test for each of them. Only the
+// answers given in the puzzle description are listed, so either part may be
+// missing
+macro_rules! examples {
+    (@answer) => {
+        None
+    };
+    (@answer $answer:literal) => {
+        Some(concat!($answer))
+    };
+    [
+        $(
+            $name:ident {
+                input: $input:expr
+                $(, part1: $part1:literal)?
+                $(, part2: $part2:literal)?
+                $(,)?
+            }
+        ),* $(,)?
+    ] => {
+        pub const EXAMPLES: &[$crate::registry::Example] = &[
+            $(
+                $crate::registry::Example {
+                    name: stringify!($name),
+                    input: $input,
+                    part1: examples!(@answer $($part1)?),
+                    part2: examples!(@answer $($part2)?),
+                }
+            ),*
+        ];
+
+        #[cfg(test)]
+        mod examples {
+            $(
+                #[test]
+                fn $name() {
+                    let example = super::EXAMPLES
+                        .iter()
+                        .find(|example| example.name == stringify!($name))
+                        .unwrap();
+
+                    $crate::registry::check_example(super::REGISTRATION.solver, example);
+                }
+            )*
+        }
+    };
+}
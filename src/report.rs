@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+use crate::{
+    history::Verdict,
+    timing::{Summary, Timings},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+
+    /// One JSON record per line, for each part that was run
+    Json,
+}
+
+// What happened to one part of one day. Fields which do not apply, like the
+// verdict when nothing was submitted, are null
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: i32,
+    pub day: u32,
+    pub part: i64,
+    pub answer: Option<String>,
+    // The answer of the example, or the accepted answer when verifying
+    pub expected: Option<String>,
+    pub parse_time: Option<Summary>,
+    pub solve_time: Option<Summary>,
+    pub verdict: Option<Verdict>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(year: i32, day: u32, part: i64) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            parse_time: None,
+            solve_time: None,
+            verdict: None,
+            error: None,
+        }
+    }
+
+    pub fn timed(self, parse_time: &Timings, solve_time: &Timings) -> Record {
+        Record {
+            parse_time: parse_time.summary(),
+            solve_time: solve_time.summary(),
+            ..self
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_one_line_per_record() {
+        let record = Record {
+            answer: Some(String::from("11")),
+            verdict: Some(Verdict::TooHigh),
+            ..Record::new(2024, 1, 1)
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            "{\"year\":2024,\"day\":1,\"part\":1,\"answer\":\"11\",\"expected\":null,\
+             \"parse_time\":null,\"solve_time\":null,\"verdict\":\"too-high\",\"error\":null}"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::{Path, PathBuf};
+
+// Where the build script looks for the module of a day, see build.rs
+pub fn module_path(src_dir: &Path, year: i32, day: u32) -> PathBuf {
+    src_dir
+        .join(format!("aoc{:02}", year % 100))
+        .join(format!("day{day:02}.rs"))
+}
+
+// The body of a string literal starting with "\<newline>", like the inputs of
+// the examples. The whitespace right after the line break would be skipped by
+// the compiler, so it is escaped as well
+fn string_literal_body(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"");
+
+    match escaped.chars().next() {
+        Some(first) if first.is_whitespace() && first != '\n' => {
+            format!("\\u{{{:x}}}{}", first as u32, &escaped[first.len_utf8()..])
+        }
+        _ => escaped,
+    }
+}
+
+// A module with a stub for each part, which builds and registers itself as is.
+// The examples are listed without their answers, which have to be read in the
+// puzzle description
+pub fn day_module(year: i32, day: u32, title: &str, examples: &[String]) -> String {
+    let name = format!("Day{day:02}");
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let examples = (1..)
+        .zip(examples)
+        .map(|(number, input)| {
+            format!(
+                "    example{number} {{\n        input: \"\\\n{}\",\n    }},\n",
+                string_literal_body(input)
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "\
+use crate::puzzle::{{Puzzle, PuzzleError, PuzzleResult}};
+
+#[derive(Clone)]
+pub struct {name} {{
+    lines: Vec<String>,
+}}
+
+register_puzzle!({name}, year = {year}, day = {day}, title = \"{title}\");
+
+// Add the answers given in the puzzle description as `part1: ...` and
+// `part2: ...` to test them
+examples![
+{examples}];
+
+impl Puzzle for {name} {{
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> PuzzleResult<Self> {{
+        Ok({name} {{
+            lines: input.lines().map(String::from).collect(),
+        }})
+    }}
+
+    fn part1(self) -> PuzzleResult<i64> {{
+        Err(PuzzleError::new(format!(
+            \"Part 1 is not solved yet, {{}} lines to go through\",
+            self.lines.len()
+        )))
+    }}
+
+    fn part2(self) -> PuzzleResult<i64> {{
+        Err(PuzzleError::new(format!(
+            \"Part 2 is not solved yet, {{}} lines to go through\",
+            self.lines.len()
+        )))
+    }}
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_examples_intact() {
+        let module = day_module(2024, 5, "Say \"Hi\"", &[String::from("  a\\b\n\"c\"\n")]);
+
+        assert_eq!(
+            module_path(Path::new("src"), 2024, 5),
+            Path::new("src/aoc24/day05.rs")
+        );
+        assert!(module.contains(
+            "register_puzzle!(Day05, year = 2024, day = 5, title = \"Say \\\"Hi\\\"\");"
+        ));
+        assert!(module.contains("input: \"\\\n\\u{20} a\\\\b\n\\\"c\\\"\n\",\n"));
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+// Shortest paths from a start state, in a graph given by a closure listing the
+// successors of a state along with the cost of moving to each of them. Costs
+// must be positive.
+//
+// The search stops once every goal at the shortest distance is reached, so
+// that all of them are known. Without any goal, every reachable state is
+// explored
+pub struct Search<S> {
+    distances: HashMap<S, i64>,
+    // Every state through which a state can be reached at its distance
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+// A state waiting to be explored. The priority queue depends on `Ord`, which
+// is reversed so that the queue becomes a min-heap instead of a max-heap
+struct Queued<S> {
+    priority: i64,
+    distance: i64,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// Explores the states in the order of their distance plus `heuristic`, which
+// must never overestimate the distance to the closest goal, nor decrease by
+// more than the cost of a move
+pub fn a_star<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> i64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+    let mut tentative_distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        distance: 0,
+        state: start,
+    }]);
+
+    while let Some(Queued {
+        priority,
+        distance,
+        state,
+    }) = queue.pop()
+    {
+        if search.goal_distance().is_some_and(|best| priority > best) {
+            break;
+        }
+
+        // A state is queued again whenever a shorter way to it is found, only
+        // the first time it comes out of the queue counts
+        if search.distances.contains_key(&state) {
+            continue;
+        }
+        search.distances.insert(state.clone(), distance);
+
+        // There is no point in going past a goal
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+
+            match tentative_distances.get(&next) {
+                Some(&known) if known < next_distance => (),
+                Some(&known) if known == next_distance => {
+                    search.predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    tentative_distances.insert(next.clone(), next_distance);
+                    search.predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued {
+                        priority: next_distance + heuristic(&next),
+                        distance: next_distance,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    search
+}
+
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+{
+    a_star(start, successors, |_| 0, is_goal)
+}
+
+// Every move costs 1
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    dijkstra(
+        start,
+        |state: &S| successors(state).into_iter().map(|next| (next, 1)),
+        is_goal,
+    )
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    // Only known for the states explored before the search stopped
+    pub fn distance(&self, state: &S) -> Option<i64> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<i64> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    // The states right before `state` on its shortest paths
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    // One of the shortest paths from the start to `state`, both included
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distance(state)?;
+
+        let mut path = vec![state.clone()];
+
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    // One of the shortest paths to the closest goal
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    // Every state on any of the shortest paths to the closest goals
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut left_to_visit = self.goals.clone();
+
+        while let Some(state) = left_to_visit.pop() {
+            if states.insert(state.clone()) {
+                left_to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A diamond 0 -> 1 -> 3 and 0 -> 2 -> 3 of equal costs, with a longer
+    // detour 0 -> 4 -> 3
+    fn diamond(state: &i64) -> Vec<(i64, i64)> {
+        match state {
+            0 => vec![(1, 1), (2, 2), (4, 1)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_every_shortest_path() {
+        let search = dijkstra(0, diamond, |&state| state == 3);
+
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.predecessors(&3), [1, 2]);
+        assert_eq!(search.path(), Some(vec![0, 1, 3]));
+        assert_eq!(search.on_optimal_paths(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn explores_everything_without_a_goal() {
+        let search = bfs(0, |&state| [state + 1].into_iter().filter(|&next| next < 5), |_| false);
+
+        assert_eq!(search.distance(&4), Some(4));
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(search.goal_distance(), None);
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::read_to_string,
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use regex::Regex;
+
+use crate::backend::judge;
+
+// A stand-in for the AoC website serving what is stored in a fixtures
+// directory, laid out as follows:
+//  - `<year>/<dd>.txt` is the puzzle input
+//  - `<year>/<dd>.html` is the puzzle page
+//  - `<year>/<dd>.answers` holds the answer of part 1 on its first line and
+//    the answer of part 2 on its second line
+//  - `session`, if present, holds the only session cookie that is accepted.
+//    Otherwise any session cookie is
+//
+// Like the real website, it only accepts an answer for the first unsolved
+// part and makes you wait before answering again after a wrong answer
+pub struct StandInServer {
+    fixtures: PathBuf,
+    cooldown: Duration,
+    solved: HashSet<(i32, u32, i64)>,
+    wait_until: Option<Instant>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    session: Option<String>,
+    body: String,
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Response {
+            status: "200 OK",
+            body,
+        }
+    }
+
+    fn bad_request(body: &str) -> Self {
+        Response {
+            status: "400 Bad Request",
+            body: String::from(body),
+        }
+    }
+
+    fn not_found() -> Self {
+        Response {
+            status: "404 Not Found",
+            body: String::from("404 Not Found"),
+        }
+    }
+
+    // The outcome of a submission is what appears in the `main` element of
+    // the page
+    fn article(message: &str) -> Self {
+        Response::ok(format!(
+            "<!DOCTYPE html>\n<html><body>\n<main>\n<article><p>{message}</p></article>\n</main>\n</body></html>\n"
+        ))
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> anyhow::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut words = request_line.split_whitespace();
+    let method = String::from(words.next().unwrap_or_default());
+    let path = String::from(words.next().unwrap_or_default());
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        match line.trim_end().split_once(':') {
+            Some((name, value)) => {
+                headers.insert(name.trim().to_lowercase(), String::from(value.trim()));
+            }
+            None => break,
+        }
+    }
+
+    let session = headers.get("cookie").and_then(|cookies| {
+        cookies
+            .split(';')
+            .filter_map(|cookie| cookie.trim().strip_prefix("session="))
+            .map(String::from)
+            .next()
+    });
+
+    let length = headers
+        .get("content-length")
+        .and_then(|length| length.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        session,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+// Decodes an `application/x-www-form-urlencoded` value
+fn decode_form_value(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        match byte {
+            b'+' => {
+                bytes.push(b' ');
+                rest = tail;
+            }
+            b'%' if tail.len() >= 2 => {
+                match u8::from_str_radix(&String::from_utf8_lossy(&tail[..2]), 16) {
+                    Ok(decoded) => bytes.push(decoded),
+                    Err(_) => bytes.extend_from_slice(&rest[..3]),
+                }
+                rest = &tail[2..];
+            }
+            _ => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn form_value(body: &str, name: &str) -> Option<String> {
+    body.split('&')
+        .filter_map(|field| field.split_once('='))
+        .find(|(field_name, _)| *field_name == name)
+        .map(|(_, value)| decode_form_value(value))
+}
+
+// AoC shows the remaining time as "47s" or "1m 7s"
+fn format_wait(remaining: Duration) -> String {
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+
+    if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+impl StandInServer {
+    pub fn new(fixtures: &Path, cooldown: Duration) -> Self {
+        StandInServer {
+            fixtures: PathBuf::from(fixtures),
+            cooldown,
+            solved: HashSet::new(),
+            wait_until: None,
+        }
+    }
+
+    // Serves one request at a time, forever
+    pub fn serve(&mut self, address: &str) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(address)?;
+        println!("Serving {} on http://{}", self.fixtures.display(), listener.local_addr()?);
+
+        for stream in listener.incoming() {
+            let mut stream = stream?;
+
+            let response = match read_request(&mut stream) {
+                Ok(request) => {
+                    let response = self.respond(&request);
+                    println!("{} {} -> {}", request.method, request.path, response.status);
+                    response
+                }
+                Err(error) => Response::bad_request(&format!("{error}")),
+            };
+
+            // The client may already be gone, which is its problem
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{}",
+                response.status,
+                response.body.len(),
+                response.body
+            );
+        }
+
+        Ok(())
+    }
+
+    fn fixture(&self, year: i32, day: u32, extension: &str) -> Option<String> {
+        read_to_string(
+            self.fixtures
+                .join(format!("{year}"))
+                .join(format!("{day:02}.{extension}")),
+        )
+        .ok()
+    }
+
+    fn logged_in(&self, request: &Request) -> bool {
+        match (
+            read_to_string(self.fixtures.join("session")),
+            &request.session,
+        ) {
+            (_, None) => false,
+            (Ok(expected), Some(session)) => expected.trim() == session,
+            (Err(_), Some(_)) => true,
+        }
+    }
+
+    fn respond(&mut self, request: &Request) -> Response {
+        let route = Regex::new(r"^/(?<year>\d+)/day/(?<day>\d+)(?<page>/input|/answer)?$").unwrap();
+
+        let Some(captures) = route.captures(&request.path) else {
+            return Response::not_found();
+        };
+        let (Ok(year), Ok(day)) = (captures["year"].parse::<i32>(), captures["day"].parse::<u32>())
+        else {
+            return Response::not_found();
+        };
+
+        match (request.method.as_str(), captures.name("page").map(|page| page.as_str())) {
+            ("GET", None) => self
+                .fixture(year, day, "html")
+                .map_or_else(Response::not_found, Response::ok),
+            ("GET", Some("/input")) if self.logged_in(request) => self
+                .fixture(year, day, "txt")
+                .map_or_else(Response::not_found, Response::ok),
+            ("GET", Some("/input")) => Response::bad_request(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            ("POST", Some("/answer")) if self.logged_in(request) => {
+                self.judge_answer(year, day, request)
+            }
+            ("POST", Some("/answer")) => {
+                Response::bad_request("Please log in to submit an answer.")
+            }
+            _ => Response::not_found(),
+        }
+    }
+
+    fn judge_answer(&mut self, year: i32, day: u32, request: &Request) -> Response {
+        let (Some(level), Some(answer)) = (
+            form_value(&request.body, "level").and_then(|level| level.parse::<i64>().ok()),
+            form_value(&request.body, "answer"),
+        ) else {
+            return Response::bad_request("Expected a level and an answer");
+        };
+
+        if level != 1 && level != 2 {
+            return Response::bad_request("The level must be 1 or 2");
+        }
+
+        let Some(expected) = self
+            .fixture(year, day, "answers")
+            .and_then(|answers| answers.lines().nth(level as usize - 1).map(String::from))
+        else {
+            return Response::not_found();
+        };
+
+        if let Some(remaining) = self
+            .wait_until
+            .and_then(|wait_until| wait_until.checked_duration_since(Instant::now()))
+        {
+            return Response::article(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {} left to wait.",
+                format_wait(remaining)
+            ));
+        }
+
+        let unsolved_level = (1..=2).find(|&level| !self.solved.contains(&(year, day, level)));
+        if unsolved_level != Some(level) {
+            return Response::article(
+                "You don't seem to be solving the right level.  Did you already complete it?",
+            );
+        }
+
+        let outcome = judge(expected.trim(), &answer);
+
+        if expected.trim() == answer {
+            self.solved.insert((year, day, level));
+
+            Response::article(&outcome)
+        } else {
+            self.wait_until = Some(Instant::now() + self.cooldown);
+
+            let wait = match self.cooldown.as_secs() {
+                60 => String::from("one minute"),
+                seconds if seconds % 60 == 0 => format!("{} minutes", seconds / 60),
+                seconds => format!("{seconds} seconds"),
+            };
+
+            Response::article(&format!("{outcome} Please wait {wait} before trying again."))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir, write};
+
+    use super::*;
+    use crate::cooldown::wait_time;
+
+    // Day 1 of 2024 answers 11 and 31, and only the session "secret" is
+    // accepted
+    fn server(fixtures: &Path) -> StandInServer {
+        create_dir(fixtures.join("2024")).unwrap();
+        write(fixtures.join("2024/01.txt"), "3   4\n").unwrap();
+        write(fixtures.join("2024/01.answers"), "11\n31\n").unwrap();
+        write(fixtures.join("session"), "secret\n").unwrap();
+
+        StandInServer::new(fixtures, Duration::from_secs(60))
+    }
+
+    fn request(method: &str, path: &str, session: Option<&str>, body: &str) -> Request {
+        Request {
+            method: String::from(method),
+            path: String::from(path),
+            session: session.map(String::from),
+            body: String::from(body),
+        }
+    }
+
+    fn submit(server: &mut StandInServer, level: i64, answer: &str) -> Response {
+        server.respond(&request(
+            "POST",
+            "/2024/day/1/answer",
+            Some("secret"),
+            &format!("level={level}&answer={answer}"),
+        ))
+    }
+
+    #[test]
+    fn makes_you_wait_after_a_wrong_answer() {
+        let fixtures = tempfile::tempdir().unwrap();
+        let mut server = server(fixtures.path());
+
+        let response = submit(&mut server, 1, "12");
+        assert!(response.body.contains("your answer is too high"), "{}", response.body);
+        assert!(response.body.contains("Please wait one minute before trying again."));
+
+        let response = submit(&mut server, 1, "11");
+        assert!(response.body.contains("You gave an answer too recently"));
+        let remaining = wait_time(&response.body).unwrap();
+        assert!(remaining > Duration::ZERO && remaining <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn only_accepts_the_first_unsolved_level() {
+        let fixtures = tempfile::tempdir().unwrap();
+        let mut server = server(fixtures.path());
+        let wrong_level = "You don't seem to be solving the right level";
+
+        assert!(submit(&mut server, 2, "31").body.contains(wrong_level));
+        assert!(submit(&mut server, 1, "11").body.contains("That's the right answer!"));
+        assert!(submit(&mut server, 1, "11").body.contains(wrong_level));
+        assert!(submit(&mut server, 2, "31").body.contains("That's the right answer!"));
+
+        for level in [0, 3] {
+            assert_eq!(submit(&mut server, level, "11").status, "400 Bad Request");
+        }
+    }
+
+    #[test]
+    fn refuses_unknown_sessions() {
+        let fixtures = tempfile::tempdir().unwrap();
+        let mut server = server(fixtures.path());
+
+        for session in [None, Some("guess")] {
+            let input = server.respond(&request("GET", "/2024/day/1/input", session, ""));
+            let answer = server.respond(&request(
+                "POST",
+                "/2024/day/1/answer",
+                session,
+                "level=1&answer=11",
+            ));
+
+            assert_eq!(input.status, "400 Bad Request");
+            assert_eq!(answer.status, "400 Bad Request");
+        }
+
+        let input = server.respond(&request("GET", "/2024/day/1/input", Some("secret"), ""));
+        assert_eq!(input.body, "3   4\n");
+        assert!(!server.solved.contains(&(2024, 1, 1)));
+    }
+
+    #[test]
+    fn decodes_form_values() {
+        assert_eq!(decode_form_value("a+b%2Cc%3d"), "a b,c=");
+        assert_eq!(decode_form_value("100%"), "100%");
+        assert_eq!(decode_form_value("%zz1"), "%zz1");
+        assert_eq!(form_value("level=1&answer=x%26y", "answer").as_deref(), Some("x&y"));
+    }
+
+    #[test]
+    fn formats_the_wait_like_the_website() {
+        assert_eq!(format_wait(Duration::from_secs(47)), "47s");
+        assert_eq!(format_wait(Duration::from_millis(46_200)), "47s");
+        assert_eq!(format_wait(Duration::from_secs(67)), "1m 7s");
+    }
+}
@@ -0,0 +1,76 @@
+use std::{fmt::Display, time::Duration};
+
+use serde::Serialize;
+
+// All the durations measured for one phase (parsing or solving a part) over
+// repeated runs
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+// What the JSON output tells about some timings, in nanoseconds
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Summary {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timings {
+    pub fn single(sample: Duration) -> Timings {
+        Timings {
+            samples: vec![sample],
+        }
+    }
+
+    pub fn push(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    fn sorted(&self) -> Vec<Duration> {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        samples
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    pub fn median(&self) -> Option<Duration> {
+        let samples = self.sorted();
+
+        samples.get(samples.len() / 2).copied()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.samples.iter().max().copied()
+    }
+
+    pub fn summary(&self) -> Option<Summary> {
+        let nanoseconds = |duration: Duration| duration.as_nanos() as u64;
+
+        Some(Summary {
+            runs: self.samples.len(),
+            min_ns: nanoseconds(self.min()?),
+            median_ns: nanoseconds(self.median()?),
+            max_ns: nanoseconds(self.max()?),
+        })
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.min(), self.median(), self.max()) {
+            (Some(min), Some(median), Some(max)) if self.samples.len() > 1 => write!(
+                f,
+                "min {min:.2?}, median {median:.2?}, max {max:.2?} over {} runs",
+                self.samples.len()
+            ),
+            (Some(single), _, _) => write!(f, "{single:.2?}"),
+            _ => write!(f, "not measured"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    io::{stderr, Write as _},
+    thread::sleep,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+// Days between 1970-01-01 and a date, see
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Puzzles unlock at midnight EST, which is 5:00 UTC
+pub fn unlock_time(year: i32, day: u32) -> SystemTime {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+
+    UNIX_EPOCH + Duration::from_secs((days * 86_400 + 5 * 3_600) as u64)
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+// Shows a countdown until the puzzle unlocks, if it is not unlocked yet. It
+// goes to the standard error, like any progress report
+pub fn wait_for_unlock(year: i32, day: u32) {
+    let unlock = unlock_time(year, day);
+    let mut waited = false;
+
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        eprint!(
+            "\rDay {day} of year {year} unlocks in {}",
+            format_countdown(remaining)
+        );
+        let _ = stderr().flush();
+
+        // Wakes up on the next second of the countdown
+        let until_next_second = Duration::from_nanos(u64::from(remaining.subsec_nanos()));
+        sleep(if until_next_second.is_zero() {
+            Duration::from_secs(1)
+        } else {
+            until_next_second
+        });
+        waited = true;
+    }
+
+    if waited {
+        eprintln!("\rDay {day} of year {year} is unlocked        ");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let seconds = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        assert_eq!(seconds(unlock_time(2024, 1)), 1_733_029_200);
+        assert_eq!(seconds(unlock_time(2015, 25)), 1_451_019_600);
+    }
+
+    #[test]
+    fn formats_the_countdown() {
+        assert_eq!(format_countdown(Duration::from_millis(3_723_500)), "01:02:04");
+        assert_eq!(format_countdown(Duration::from_secs(2 * 86_400 + 59)), "2d 00:00:59");
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::metadata,
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+// Notices when a file changes by polling its modification time, which every
+// way of saving a file updates, even replacing it with a new one
+pub struct FileWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> Self {
+        FileWatcher {
+            path: PathBuf::from(path),
+            modified: Self::modified(path),
+        }
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    // The file may briefly disappear while an editor saves it, which does not
+    // count as a change
+    pub fn wait_for_change(&mut self) {
+        loop {
+            sleep(Duration::from_millis(300));
+
+            let modified = Self::modified(&self.path);
+
+            if modified.is_some() && modified != self.modified {
+                self.modified = modified;
+                return;
+            }
+        }
+    }
+}
+
+// How an answer compares to the one of the previous run, if any
+pub fn compare(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => String::from(current),
+        Some(previous) if previous == current => format!("{current}, unchanged"),
+        Some(previous) => format!("{current}, was {previous}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_with_the_previous_answer() {
+        assert_eq!(compare(None, "11"), "11");
+        assert_eq!(compare(Some("11"), "11"), "11, unchanged");
+        assert_eq!(compare(Some("12"), "failed"), "failed, was 12");
+    }
+}